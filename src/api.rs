@@ -0,0 +1,457 @@
+//! A trait abstracting over the Plaid API, to mock it in tests.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::*;
+
+/// The Plaid API endpoints provided by `Client`.
+///
+/// Code depending on `impl PlaidApi` (or `dyn PlaidApi`) rather than on
+/// `Client` can be tested against a hand-written or generated mock instead of
+/// the Plaid API. The Sandbox-only endpoints are not part of this trait, nor
+/// are `wait_for_product` and `item`, which are tied to `Client` itself.
+#[async_trait]
+pub trait PlaidApi: Send + Sync {
+    /// See `Client::create_link_token`.
+    async fn create_link_token(
+        &self,
+        request: &CreateLinkTokenRequest,
+    ) -> Result<CreateLinkTokenResponse, Error>;
+
+    /// See `Client::create_update_link_token`.
+    async fn create_update_link_token(
+        &self,
+        access_token: &str,
+        client_name: &str,
+        language: SupportedLanguage,
+        country_codes: Vec<SupportedCountry>,
+        user: EndUser,
+    ) -> Result<CreateLinkTokenResponse, Error>;
+
+    /// See `Client::exchange_public_token`.
+    async fn exchange_public_token(
+        &self,
+        public_token: &str,
+    ) -> Result<ExchangePublicTokenResponse, Error>;
+
+    /// See `Client::create_processor_token`.
+    async fn create_processor_token(
+        &self,
+        access_token: &str,
+        account_id: &str,
+        processor: SupportedProcessor,
+    ) -> Result<CreateProcessorTokenResponse, Error>;
+
+    /// See `Client::institution`.
+    async fn institution(
+        &self,
+        institution_id: &str,
+        country_codes: &[SupportedCountry],
+        options: InstitutionRequestOptions,
+    ) -> Result<InstitutionResponse, Error>;
+
+    /// See `Client::institutions`.
+    async fn institutions(
+        &self,
+        count: u32,
+        offset: u32,
+        country_codes: &[SupportedCountry],
+        products: &[Product],
+    ) -> Result<InstitutionsGetResponse, Error>;
+
+    /// See `Client::institutions_all`.
+    async fn institutions_all(
+        &self,
+        country_codes: &[SupportedCountry],
+        products: &[Product],
+    ) -> Result<Vec<Institution>, Error>;
+
+    /// See `Client::institutions_search`.
+    async fn institutions_search(
+        &self,
+        query: &str,
+        country_codes: &[SupportedCountry],
+        products: &[Product],
+    ) -> Result<InstitutionsSearchResponse, Error>;
+
+    /// See `Client::accounts`.
+    async fn accounts(&self, access_token: &str) -> Result<AccountsResponse, Error>;
+
+    /// See `Client::accounts_with_options`.
+    async fn accounts_with_options(
+        &self,
+        access_token: &str,
+        options: AccountsGetRequestOptions,
+    ) -> Result<AccountsResponse, Error>;
+
+    /// See `Client::accounts_raw`.
+    async fn accounts_raw(
+        &self,
+        access_token: &str,
+    ) -> Result<(AccountsResponse, serde_json::Value), Error>;
+
+    /// See `Client::accounts_multi`.
+    async fn accounts_multi(
+        &self,
+        tokens: &[String],
+        concurrency: usize,
+    ) -> Vec<(String, Result<AccountsResponse, Error>)>;
+
+    /// See `Client::balance`.
+    async fn balance(
+        &self,
+        access_token: &str,
+        options: BalanceRequestOptions,
+    ) -> Result<AccountsResponse, Error>;
+
+    /// See `Client::auth`.
+    async fn auth(
+        &self,
+        access_token: &str,
+        options: AuthRequestOptions,
+    ) -> Result<AuthResponse, Error>;
+
+    /// See `Client::identity`.
+    async fn identity(
+        &self,
+        access_token: &str,
+        options: IdentityRequestOptions,
+    ) -> Result<AccountsResponse, Error>;
+
+    /// See `Client::investments_refresh`.
+    async fn investments_refresh(
+        &self,
+        access_token: &str,
+    ) -> Result<InvestmentsRefreshResponse, Error>;
+
+    /// See `Client::transactions`.
+    async fn transactions(
+        &self,
+        access_token: &str,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        options: TransactionsRequestOptions,
+    ) -> Result<TransactionsGetResponse, Error>;
+
+    /// See `Client::transactions_sync`.
+    async fn transactions_sync(
+        &self,
+        access_token: &str,
+        cursor: Option<&str>,
+    ) -> Result<TransactionsSyncResponse, SyncError>;
+
+    /// See `Client::transactions_all`.
+    async fn transactions_all(&self, access_token: &str) -> Result<Vec<Transaction>, Error>;
+
+    /// See `Client::transfer_authorization_create`.
+    async fn transfer_authorization_create(
+        &self,
+        request: &TransferAuthorizationCreateRequest,
+    ) -> Result<TransferAuthorizationCreateResponse, Error>;
+
+    /// See `Client::user_create`.
+    async fn user_create(
+        &self,
+        client_user_id: &str,
+        consumer_report_user_identity: Option<&ConsumerReportUserIdentity>,
+    ) -> Result<UserCreateResponse, Error>;
+
+    /// See `Client::asset_report_create`.
+    async fn asset_report_create(
+        &self,
+        request: &AssetReportCreateRequest,
+    ) -> Result<AssetReportCreateResponse, Error>;
+
+    /// See `Client::asset_report_get`.
+    async fn asset_report_get(
+        &self,
+        asset_report_token: &str,
+        include_insights: bool,
+    ) -> Result<AssetReportGetResponse, Error>;
+
+    /// See `Client::asset_report_pdf`.
+    async fn asset_report_pdf(
+        &self,
+        asset_report_token: &str,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<u8>, Error>;
+
+    /// See `Client::item_get`.
+    async fn item_get(&self, access_token: &str) -> Result<ItemGetResponse, Error>;
+
+    /// See `Client::item_health`.
+    async fn item_health(&self, access_token: &str) -> Result<ItemHealth, Error>;
+
+    /// See `Client::item_remove`.
+    async fn item_remove(&self, access_token: &str) -> Result<ItemRemoveResponse, Error>;
+
+    /// See `Client::transfer_list`.
+    async fn transfer_list(
+        &self,
+        request: &TransferListRequest,
+    ) -> Result<TransferListResponse, Error>;
+
+    /// See `Client::payment_initiation_payment_list`.
+    async fn payment_initiation_payment_list(
+        &self,
+        count: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<PaymentListResponse, Error>;
+
+    /// See `Client::update_webhook`.
+    async fn update_webhook(
+        &self,
+        access_token: &str,
+        webhook_url: &str,
+    ) -> Result<WebhookUpdateResponse, Error>;
+}
+
+#[async_trait]
+impl PlaidApi for Client {
+    async fn create_link_token(
+        &self,
+        request: &CreateLinkTokenRequest,
+    ) -> Result<CreateLinkTokenResponse, Error> {
+        Client::create_link_token(self, request).await
+    }
+
+    async fn create_update_link_token(
+        &self,
+        access_token: &str,
+        client_name: &str,
+        language: SupportedLanguage,
+        country_codes: Vec<SupportedCountry>,
+        user: EndUser,
+    ) -> Result<CreateLinkTokenResponse, Error> {
+        Client::create_update_link_token(
+            self,
+            access_token,
+            client_name,
+            language,
+            country_codes,
+            user,
+        )
+        .await
+    }
+
+    async fn exchange_public_token(
+        &self,
+        public_token: &str,
+    ) -> Result<ExchangePublicTokenResponse, Error> {
+        Client::exchange_public_token(self, public_token).await
+    }
+
+    async fn create_processor_token(
+        &self,
+        access_token: &str,
+        account_id: &str,
+        processor: SupportedProcessor,
+    ) -> Result<CreateProcessorTokenResponse, Error> {
+        Client::create_processor_token(self, access_token, account_id, processor).await
+    }
+
+    async fn institution(
+        &self,
+        institution_id: &str,
+        country_codes: &[SupportedCountry],
+        options: InstitutionRequestOptions,
+    ) -> Result<InstitutionResponse, Error> {
+        Client::institution(self, institution_id, country_codes, options).await
+    }
+
+    async fn institutions(
+        &self,
+        count: u32,
+        offset: u32,
+        country_codes: &[SupportedCountry],
+        products: &[Product],
+    ) -> Result<InstitutionsGetResponse, Error> {
+        Client::institutions(self, count, offset, country_codes, products).await
+    }
+
+    async fn institutions_all(
+        &self,
+        country_codes: &[SupportedCountry],
+        products: &[Product],
+    ) -> Result<Vec<Institution>, Error> {
+        Client::institutions_all(self, country_codes, products).await
+    }
+
+    async fn institutions_search(
+        &self,
+        query: &str,
+        country_codes: &[SupportedCountry],
+        products: &[Product],
+    ) -> Result<InstitutionsSearchResponse, Error> {
+        Client::institutions_search(self, query, country_codes, products).await
+    }
+
+    async fn accounts(&self, access_token: &str) -> Result<AccountsResponse, Error> {
+        Client::accounts(self, access_token).await
+    }
+
+    async fn accounts_with_options(
+        &self,
+        access_token: &str,
+        options: AccountsGetRequestOptions,
+    ) -> Result<AccountsResponse, Error> {
+        Client::accounts_with_options(self, access_token, options).await
+    }
+
+    async fn accounts_raw(
+        &self,
+        access_token: &str,
+    ) -> Result<(AccountsResponse, serde_json::Value), Error> {
+        Client::accounts_raw(self, access_token).await
+    }
+
+    async fn accounts_multi(
+        &self,
+        tokens: &[String],
+        concurrency: usize,
+    ) -> Vec<(String, Result<AccountsResponse, Error>)> {
+        Client::accounts_multi(self, tokens, concurrency).await
+    }
+
+    async fn balance(
+        &self,
+        access_token: &str,
+        options: BalanceRequestOptions,
+    ) -> Result<AccountsResponse, Error> {
+        Client::balance(self, access_token, options).await
+    }
+
+    async fn auth(
+        &self,
+        access_token: &str,
+        options: AuthRequestOptions,
+    ) -> Result<AuthResponse, Error> {
+        Client::auth(self, access_token, options).await
+    }
+
+    async fn identity(
+        &self,
+        access_token: &str,
+        options: IdentityRequestOptions,
+    ) -> Result<AccountsResponse, Error> {
+        Client::identity(self, access_token, options).await
+    }
+
+    async fn investments_refresh(
+        &self,
+        access_token: &str,
+    ) -> Result<InvestmentsRefreshResponse, Error> {
+        Client::investments_refresh(self, access_token).await
+    }
+
+    async fn transactions(
+        &self,
+        access_token: &str,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        options: TransactionsRequestOptions,
+    ) -> Result<TransactionsGetResponse, Error> {
+        Client::transactions(self, access_token, start_date, end_date, options).await
+    }
+
+    async fn transactions_sync(
+        &self,
+        access_token: &str,
+        cursor: Option<&str>,
+    ) -> Result<TransactionsSyncResponse, SyncError> {
+        Client::transactions_sync(self, access_token, cursor).await
+    }
+
+    async fn transactions_all(&self, access_token: &str) -> Result<Vec<Transaction>, Error> {
+        Client::transactions_all(self, access_token).await
+    }
+
+    async fn transfer_authorization_create(
+        &self,
+        request: &TransferAuthorizationCreateRequest,
+    ) -> Result<TransferAuthorizationCreateResponse, Error> {
+        Client::transfer_authorization_create(self, request).await
+    }
+
+    async fn user_create(
+        &self,
+        client_user_id: &str,
+        consumer_report_user_identity: Option<&ConsumerReportUserIdentity>,
+    ) -> Result<UserCreateResponse, Error> {
+        Client::user_create(self, client_user_id, consumer_report_user_identity).await
+    }
+
+    async fn asset_report_create(
+        &self,
+        request: &AssetReportCreateRequest,
+    ) -> Result<AssetReportCreateResponse, Error> {
+        Client::asset_report_create(self, request).await
+    }
+
+    async fn asset_report_get(
+        &self,
+        asset_report_token: &str,
+        include_insights: bool,
+    ) -> Result<AssetReportGetResponse, Error> {
+        Client::asset_report_get(self, asset_report_token, include_insights).await
+    }
+
+    async fn asset_report_pdf(
+        &self,
+        asset_report_token: &str,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<u8>, Error> {
+        Client::asset_report_pdf(self, asset_report_token, timeout).await
+    }
+
+    async fn transfer_list(
+        &self,
+        request: &TransferListRequest,
+    ) -> Result<TransferListResponse, Error> {
+        Client::transfer_list(self, request).await
+    }
+
+    async fn payment_initiation_payment_list(
+        &self,
+        count: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<PaymentListResponse, Error> {
+        Client::payment_initiation_payment_list(self, count, cursor).await
+    }
+
+    async fn item_get(&self, access_token: &str) -> Result<ItemGetResponse, Error> {
+        Client::item_get(self, access_token).await
+    }
+
+    async fn item_health(&self, access_token: &str) -> Result<ItemHealth, Error> {
+        Client::item_health(self, access_token).await
+    }
+
+    async fn item_remove(&self, access_token: &str) -> Result<ItemRemoveResponse, Error> {
+        Client::item_remove(self, access_token).await
+    }
+
+    async fn update_webhook(
+        &self,
+        access_token: &str,
+        webhook_url: &str,
+    ) -> Result<WebhookUpdateResponse, Error> {
+        Client::update_webhook(self, access_token, webhook_url).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send<T: Send>(_: T) {}
+
+    #[test]
+    fn client_is_plaid_api() {
+        let client = Client::new("client_id", "secret".to_string(), Environment::Sandbox);
+        let api: &dyn PlaidApi = &client;
+        assert_send(api.accounts("access-sandbox-de3ce8ef-33f8-452c-a685-8671031fc0f6"));
+    }
+}
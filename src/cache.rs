@@ -0,0 +1,252 @@
+//! Opt-in caching of account metadata.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::{AccountsResponse, BalanceRequestOptions, Client, Error};
+
+/// A cache of `accounts` responses, for views that repeatedly list the
+/// accounts of an Item.
+///
+/// Account metadata (names, masks, types) rarely changes, so responses are
+/// cached per access token for the configured time to live. Balances, on the
+/// other hand, are volatile: a cached response is marked as having
+/// `balances_stale`, in which case `balance` should be used to retrieve fresh
+/// balances.
+///
+/// Real-time balance requests are rate limited per Item, so `balance` can
+/// also be throttled with `with_min_balance_refresh_interval`.
+#[derive(Debug)]
+pub struct AccountsCache {
+    client: Client,
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, AccountsResponse)>>,
+    min_balance_refresh_interval: Option<Duration>,
+    // the last real-time balances of each Item, by `item_id`
+    balances: Mutex<HashMap<String, (Instant, AccountsResponse)>>,
+    // the `item_id` of each access token whose balances were retrieved
+    item_ids: Mutex<HashMap<String, String>>,
+}
+
+/// An `AccountsResponse` that may have been served from an `AccountsCache`.
+#[derive(Clone, Debug)]
+pub struct CachedAccounts {
+    /// The response, either just retrieved or cached.
+    pub response: AccountsResponse,
+
+    /// `true` if `response` was cached, in which case its balances may be out
+    /// of date.
+    pub balances_stale: bool,
+
+    /// When `response` was retrieved.
+    pub fetched_at: Instant,
+}
+
+impl AccountsCache {
+    /// Creates an `AccountsCache` retrieving accounts with the given `Client`
+    /// and keeping them for `ttl`.
+    pub fn new(client: Client, ttl: Duration) -> Self {
+        AccountsCache {
+            client,
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+            min_balance_refresh_interval: None,
+            balances: Mutex::new(HashMap::new()),
+            item_ids: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Throttles `balance` so that the real-time balances of an Item are
+    /// retrieved at most once per `interval`. Calls within the interval return
+    /// the last real-time balances instead, with `balances_stale` set and the
+    /// `fetched_at` of the original request.
+    pub fn with_min_balance_refresh_interval(mut self, interval: Duration) -> Self {
+        self.min_balance_refresh_interval = Some(interval);
+        self
+    }
+
+    /// Returns the accounts of the Item, from the cache if they were retrieved
+    /// less than `ttl` ago.
+    pub async fn accounts(&self, access_token: &str) -> Result<CachedAccounts, Error> {
+        if let Some((fetched_at, response)) = self.cached(access_token) {
+            return Ok(CachedAccounts {
+                response,
+                balances_stale: true,
+                fetched_at,
+            });
+        }
+
+        let response = self.client.accounts(access_token).await?;
+        Ok(self.store(access_token, response))
+    }
+
+    /// Retrieves the accounts of the Item with real-time balances, refreshing
+    /// the cache.
+    ///
+    /// If throttled with `with_min_balance_refresh_interval`, returns the last
+    /// real-time balances of the Item if they were retrieved within the
+    /// interval.
+    pub async fn balance(
+        &self,
+        access_token: &str,
+        options: BalanceRequestOptions,
+    ) -> Result<CachedAccounts, Error> {
+        if let Some(cached) = self.throttled_balance(access_token, &options.account_ids) {
+            return Ok(cached);
+        }
+
+        let filtered = !options.account_ids.is_empty();
+        let response = self.client.balance(access_token, options).await?;
+        if filtered {
+            // only some accounts were retrieved; don't cache them as all of the
+            // Item's accounts
+            return Ok(CachedAccounts {
+                response,
+                balances_stale: false,
+                fetched_at: Instant::now(),
+            });
+        }
+        let cached = self.store(access_token, response);
+        if self.min_balance_refresh_interval.is_some() {
+            let item_id = cached.response.item.item_id.clone();
+            lock(&self.item_ids).insert(access_token.to_string(), item_id.clone());
+            lock(&self.balances).insert(item_id, (cached.fetched_at, cached.response.clone()));
+        }
+        Ok(cached)
+    }
+
+    /// Removes the cached accounts of the Item, e.g. after receiving a
+    /// `NEW_ACCOUNTS_AVAILABLE` webhook.
+    pub fn invalidate(&self, access_token: &str) {
+        self.entries().remove(access_token);
+    }
+
+    fn throttled_balance(
+        &self,
+        access_token: &str,
+        account_ids: &[String],
+    ) -> Option<CachedAccounts> {
+        let interval = self.min_balance_refresh_interval?;
+        let item_id = lock(&self.item_ids).get(access_token)?.clone();
+        let (fetched_at, mut response) = match lock(&self.balances).get(&item_id) {
+            Some((fetched_at, response)) if fetched_at.elapsed() < interval => {
+                (*fetched_at, response.clone())
+            }
+            _ => return None,
+        };
+
+        if !account_ids.is_empty() {
+            response
+                .accounts
+                .retain(|account| account_ids.contains(&account.account_id));
+        }
+        Some(CachedAccounts {
+            response,
+            balances_stale: true,
+            fetched_at,
+        })
+    }
+
+    fn cached(&self, access_token: &str) -> Option<(Instant, AccountsResponse)> {
+        let mut entries = self.entries();
+        match entries.get(access_token) {
+            Some((fetched_at, response)) if fetched_at.elapsed() < self.ttl => {
+                Some((*fetched_at, response.clone()))
+            }
+            Some(_) => {
+                entries.remove(access_token);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn store(&self, access_token: &str, response: AccountsResponse) -> CachedAccounts {
+        let fetched_at = Instant::now();
+        self.entries()
+            .insert(access_token.to_string(), (fetched_at, response.clone()));
+        CachedAccounts {
+            response,
+            balances_stale: false,
+            fetched_at,
+        }
+    }
+
+    fn entries(&self) -> MutexGuard<'_, HashMap<String, (Instant, AccountsResponse)>> {
+        lock(&self.entries)
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::fixtures::{accounts_response, mock_client};
+
+    const ACCESS_TOKEN: &str = "access-sandbox-de3ce8ef-33f8-452c-a685-8671031fc0f6";
+
+    #[tokio::test]
+    async fn serves_cached_accounts_with_stale_balances() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/accounts/get"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(accounts_response()))
+            .expect(2)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+        let cache = AccountsCache::new(client, Duration::from_secs(60));
+
+        let first = cache.accounts(ACCESS_TOKEN).await.unwrap();
+        assert!(!first.balances_stale);
+
+        let second = cache.accounts(ACCESS_TOKEN).await.unwrap();
+        assert!(second.balances_stale);
+        assert_eq!(second.fetched_at, first.fetched_at);
+
+        cache.invalidate(ACCESS_TOKEN);
+        let third = cache.accounts(ACCESS_TOKEN).await.unwrap();
+        assert!(!third.balances_stale);
+    }
+
+    #[tokio::test]
+    async fn throttles_balance_refreshes() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/accounts/balance/get"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(accounts_response()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+        let cache = AccountsCache::new(client, Duration::from_secs(60))
+            .with_min_balance_refresh_interval(Duration::from_secs(60));
+
+        let first = cache
+            .balance(ACCESS_TOKEN, Default::default())
+            .await
+            .unwrap();
+        assert!(!first.balances_stale);
+
+        let second = cache
+            .balance(ACCESS_TOKEN, Default::default())
+            .await
+            .unwrap();
+        assert!(second.balances_stale);
+        assert_eq!(second.fetched_at, first.fetched_at);
+
+        let options = BalanceRequestOptions {
+            account_ids: vec!["unknown".to_string()],
+            ..Default::default()
+        };
+        let filtered = cache.balance(ACCESS_TOKEN, options).await.unwrap();
+        assert!(filtered.response.accounts.is_empty());
+    }
+}
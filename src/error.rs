@@ -4,6 +4,8 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 use reqwest::Error as ReqwestError;
 use serde::{Deserialize, Serialize};
 
+use crate::{BillingAdvisory, ParseEnvironmentError};
+
 /// Represents an error that can occur when making an API request.
 #[derive(Debug)]
 pub enum Error {
@@ -12,6 +14,37 @@ pub enum Error {
 
     /// An error that ocurred during transport (using "futures-std" feature)
     TransportStd(ReqwestError),
+
+    /// A response body that could not be deserialized into the expected type
+    Deserialize(DeserializeError),
+
+    /// A response body larger than the limit set with
+    /// `ClientBuilder::max_response_size`, given in bytes
+    ResponseTooLarge(usize),
+
+    /// The Item has no account with a `verification_status`, i.e. that was
+    /// added via micro-deposits, to verify with
+    /// `sandbox_verify_first_account`
+    NoVerifiableAccount,
+
+    /// A Production `link_token` requesting many billed products, refused
+    /// by `create_link_token` unless the request acknowledges its
+    /// `billing_advisory` with `acknowledge_billing`
+    BillingNotAcknowledged(BillingAdvisory),
+}
+
+impl Error {
+    /// The Plaid `request_id` of the failed request, to quote when contacting
+    /// Plaid support.
+    ///
+    /// Only `Api` errors carry one; other errors occur without Plaid
+    /// processing the request or identifying its response.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Error::Api(error) => error.request_id.as_deref(),
+            _ => None,
+        }
+    }
 }
 
 impl From<ReqwestError> for Error {
@@ -20,6 +53,12 @@ impl From<ReqwestError> for Error {
     }
 }
 
+impl From<DeserializeError> for Error {
+    fn from(error: DeserializeError) -> Self {
+        Error::Deserialize(error)
+    }
+}
+
 impl StdError for Error {}
 
 impl Display for Error {
@@ -28,6 +67,216 @@ impl Display for Error {
     }
 }
 
+/// An error that can occur when performing a `transactions_sync` request.
+#[derive(Debug)]
+pub enum SyncError {
+    /// The cursor can no longer be used, because the Item's transactions
+    /// changed while paging (`TRANSACTIONS_SYNC_MUTATION_DURING_PAGINATION`).
+    /// Paging must restart from the cursor it started with, or from `None`,
+    /// after discarding the pages received since.
+    CursorInvalidated(ApiError),
+
+    /// Any other error.
+    Other(Error),
+}
+
+impl From<Error> for SyncError {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::Api(error)
+                if error.error_code == "TRANSACTIONS_SYNC_MUTATION_DURING_PAGINATION" =>
+            {
+                SyncError::CursorInvalidated(error)
+            }
+            error => SyncError::Other(error),
+        }
+    }
+}
+
+impl From<SyncError> for Error {
+    fn from(error: SyncError) -> Self {
+        match error {
+            SyncError::CursorInvalidated(error) => Error::Api(error),
+            SyncError::Other(error) => error,
+        }
+    }
+}
+
+impl StdError for SyncError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            SyncError::CursorInvalidated(_) => None,
+            SyncError::Other(error) => Some(error),
+        }
+    }
+}
+
+impl Display for SyncError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            SyncError::CursorInvalidated(_) => write!(
+                f,
+                "transactions changed during pagination, the sync cursor is invalidated"
+            ),
+            SyncError::Other(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+/// An error that can occur when creating a `Client` from environment
+/// variables.
+#[derive(Debug)]
+pub enum FromEnvError {
+    /// The environment variable with the given name is not set.
+    MissingVar(String),
+
+    /// The environment variable with the given name is not valid Unicode.
+    InvalidVar(String),
+
+    /// The `PLAID_ENVIRONMENT` (or prefixed equivalent) environment variable
+    /// is not a valid `Environment`.
+    InvalidEnvironment(ParseEnvironmentError),
+}
+
+impl StdError for FromEnvError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            FromEnvError::InvalidEnvironment(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Display for FromEnvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            FromEnvError::MissingVar(name) => {
+                write!(f, "environment variable `{}` is not set", name)
+            }
+            FromEnvError::InvalidVar(name) => {
+                write!(f, "environment variable `{}` is not valid Unicode", name)
+            }
+            FromEnvError::InvalidEnvironment(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+/// Serializes the error with its `kind` (`"api"`, `"transport"`,
+/// `"deserialize"`, `"response_too_large"`, `"no_verifiable_account"` or
+/// `"billing_not_acknowledged"`), e.g. to persist it:
+///
+/// - `Api` errors are serialized as their `ApiError` fields;
+/// - `TransportStd` errors as their `message`;
+/// - `Deserialize` errors as their `path` and `message`, leaving out the
+///   response body as it may contain sensitive data;
+/// - `ResponseTooLarge` errors as their `max_response_size`;
+/// - `BillingNotAcknowledged` errors as the `products` to be billed.
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        #[serde(tag = "kind", rename_all = "snake_case")]
+        enum Repr<'a> {
+            Api(&'a ApiError),
+            Transport {
+                message: String,
+            },
+            Deserialize {
+                path: &'a str,
+                message: String,
+            },
+            ResponseTooLarge {
+                max_response_size: usize,
+            },
+            NoVerifiableAccount,
+            BillingNotAcknowledged {
+                products: &'a [crate::SupportedProduct],
+            },
+        }
+
+        let repr = match self {
+            Error::Api(error) => Repr::Api(error),
+            Error::TransportStd(error) => Repr::Transport {
+                message: error.to_string(),
+            },
+            Error::Deserialize(error) => Repr::Deserialize {
+                path: error.path(),
+                message: error.source.to_string(),
+            },
+            Error::ResponseTooLarge(max_response_size) => Repr::ResponseTooLarge {
+                max_response_size: *max_response_size,
+            },
+            Error::NoVerifiableAccount => Repr::NoVerifiableAccount,
+            Error::BillingNotAcknowledged(advisory) => Repr::BillingNotAcknowledged {
+                products: &advisory.products,
+            },
+        };
+        repr.serialize(serializer)
+    }
+}
+
+/// The maximum number of bytes of a response body kept in a
+/// `DeserializeError`.
+const MAX_ERROR_BODY_LEN: usize = 1024;
+
+/// An error that occurred while deserializing a response body.
+///
+/// Carries the path to the field that failed to deserialize (e.g.
+/// `accounts[3].balances.current`), which helps to diagnose mismatches between
+/// Plaid's schema and the types in this crate.
+#[derive(Debug)]
+pub struct DeserializeError {
+    path: String,
+    body: String,
+    source: serde_json::Error,
+}
+
+impl DeserializeError {
+    pub(crate) fn new(error: serde_path_to_error::Error<serde_json::Error>, body: &[u8]) -> Self {
+        let mut body = String::from_utf8_lossy(body).into_owned();
+        if body.len() > MAX_ERROR_BODY_LEN {
+            let mut end = MAX_ERROR_BODY_LEN;
+            while !body.is_char_boundary(end) {
+                end -= 1;
+            }
+            body.truncate(end);
+        }
+
+        DeserializeError {
+            path: error.path().to_string(),
+            body,
+            source: error.into_inner(),
+        }
+    }
+
+    /// The path to the field that could not be deserialized.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The raw response body, truncated to its first 1024 bytes.
+    ///
+    /// *Note*: The body may contain sensitive data (such as account numbers)
+    /// and should be handled accordingly.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+}
+
+impl StdError for DeserializeError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl Display for DeserializeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "invalid response at `{}`: {}", self.path, self.source)
+    }
+}
+
 /// See [Error Schema](https://plaid.com/docs/errors/#error-schema)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiError {
@@ -56,6 +305,67 @@ pub struct ApiError {
 
     /// Suggested steps for resolving the error.
     pub suggested_action: Option<String>,
+
+    /// The HTTP status code associated with the error. This will only be
+    /// returned in the response body when the error information is provided
+    /// via a webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+
+    /// In the Assets product, a request can pertain to more than one Item. If
+    /// an error is returned for such a request, `causes` will contain a
+    /// breakdown of these errors on the individual Item level, if any can be
+    /// identified.
+    ///
+    /// Only provided for the error type `ASSET_REPORT_ERROR`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub causes: Vec<ApiErrorCause>,
+}
+
+/// The message returned by `ApiError::user_message` when Plaid provides no
+/// `display_message`.
+pub const DEFAULT_USER_MESSAGE: &str = "We couldn't complete your request.";
+
+impl ApiError {
+    /// A message that is safe to show to end users: the `display_message` if
+    /// any, otherwise `DEFAULT_USER_MESSAGE`.
+    ///
+    /// Never returns `error_message`, which is meant for developers and may
+    /// leak internal details.
+    pub fn user_message(&self) -> &str {
+        match self.display_message.as_deref() {
+            Some(message) if !message.trim().is_empty() => message,
+            _ => DEFAULT_USER_MESSAGE,
+        }
+    }
+
+    /// Returns `true` if `status` is a client error (`400`–`499`).
+    pub fn is_client_error(&self) -> bool {
+        self.status
+            .is_some_and(|status| (400..500).contains(&status))
+    }
+
+    /// Returns `true` if `status` is a server error (`500`–`599`).
+    pub fn is_server_error(&self) -> bool {
+        self.status
+            .is_some_and(|status| (500..600).contains(&status))
+    }
+
+    /// Returns `true` if `status` is `429 Too Many Requests`.
+    pub fn is_rate_limited(&self) -> bool {
+        self.status == Some(429)
+    }
+}
+
+/// The cause of an `ApiError` pertaining to an individual Item.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiErrorCause {
+    /// The `item_id` of the Item the error pertains to.
+    pub item_id: Option<String>,
+
+    /// The error for the individual Item.
+    #[serde(flatten)]
+    pub error: ApiError,
 }
 
 /// See [Error Type](https://plaid.com/docs/errors/#Error-error-type)
@@ -115,3 +425,175 @@ pub enum ErrorType {
     #[serde(other)]
     Unknown,
 }
+
+impl ErrorType {
+    /// The HTTP status code a service built on Plaid should respond with when
+    /// a request fails with this type of error.
+    ///
+    /// Errors caused by the request, the Item or the user map to `400`, rate
+    /// limiting to `429`, and failures of Plaid or the financial institution
+    /// to `502`, since they are failures of an upstream service. Unknown
+    /// errors map to `500`.
+    pub fn suggested_http_status(&self) -> u16 {
+        match self {
+            ErrorType::RateLimitExceeded => 429,
+            ErrorType::ApiError | ErrorType::InstitutionError => 502,
+            ErrorType::Unknown => 500,
+            ErrorType::ItemError
+            | ErrorType::AssetReportError
+            | ErrorType::PaymentError
+            | ErrorType::BankTransferError
+            | ErrorType::DepositSwitchError
+            | ErrorType::IncomeVerificationError
+            | ErrorType::SandboxError
+            | ErrorType::InvalidRequest
+            | ErrorType::InvalidInput
+            | ErrorType::InvalidResult
+            | ErrorType::RecaptchaError
+            | ErrorType::OauthError => 400,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn can_deserialize_webhook_error() {
+        let error: ApiError = serde_json::from_value(json!({
+            "display_message": null,
+            "error_code": "PRODUCT_NOT_ENABLED",
+            "error_message": "the 'assets' product is not enabled for the following access tokens",
+            "error_type": "ASSET_REPORT_ERROR",
+            "request_id": "m8MDnv9okwxFNBV",
+            "causes": [
+                {
+                    "item_id": "pZ942ZA0npFEa0BgLCfwN6tpNX0Zb9vKmYZqT",
+                    "display_message": null,
+                    "error_code": "PRODUCT_NOT_ENABLED",
+                    "error_message": "the 'assets' product is not enabled",
+                    "error_type": "ASSET_REPORT_ERROR",
+                    "request_id": "m8MDnv9okwxFNBV"
+                }
+            ],
+            "status": 400
+        }))
+        .unwrap();
+
+        assert_eq!(error.error_type, ErrorType::AssetReportError);
+        assert_eq!(error.status, Some(400));
+        assert_eq!(error.user_message(), DEFAULT_USER_MESSAGE);
+        assert_eq!(
+            error.causes[0].item_id.as_deref(),
+            Some("pZ942ZA0npFEa0BgLCfwN6tpNX0Zb9vKmYZqT")
+        );
+    }
+
+    #[test]
+    fn gets_request_id_of_api_errors() {
+        let error: ApiError = serde_json::from_value(json!({
+            "display_message": null,
+            "error_code": "PRODUCT_NOT_READY",
+            "error_message": "the requested product is not yet ready",
+            "error_type": "ITEM_ERROR",
+            "request_id": "m8MDnv9okwxFNBV"
+        }))
+        .unwrap();
+        assert_eq!(Error::Api(error).request_id(), Some("m8MDnv9okwxFNBV"));
+        assert_eq!(Error::ResponseTooLarge(1024).request_id(), None);
+    }
+
+    #[test]
+    fn detects_invalidated_sync_cursor() {
+        let error = |error_code: &str| -> Error {
+            Error::Api(
+                serde_json::from_value(json!({
+                    "display_message": null,
+                    "error_code": error_code,
+                    "error_message": "Underlying transaction data changed since last page was fetched. Please restart pagination from last update.",
+                    "error_type": "TRANSACTIONS_ERROR",
+                    "request_id": "m8MDnv9okwxFNBV"
+                }))
+                .unwrap(),
+            )
+        };
+
+        let sync_error = SyncError::from(error("TRANSACTIONS_SYNC_MUTATION_DURING_PAGINATION"));
+        assert!(matches!(sync_error, SyncError::CursorInvalidated(_)));
+        assert!(matches!(Error::from(sync_error), Error::Api(_)));
+
+        let sync_error = SyncError::from(error("PRODUCT_NOT_READY"));
+        assert!(matches!(sync_error, SyncError::Other(Error::Api(_))));
+    }
+
+    #[test]
+    fn can_deserialize_response_error() {
+        let error: ApiError = serde_json::from_value(json!({
+            "display_message": null,
+            "error_code": "INVALID_API_KEYS",
+            "error_message": "invalid client_id or secret provided",
+            "error_type": "INVALID_INPUT",
+            "request_id": "m8MDnv9okwxFNBV",
+            "documentation_url": "https://plaid.com/docs/?ref=error#invalid-input-errors",
+            "suggested_action": null
+        }))
+        .unwrap();
+
+        assert_eq!(error.status, None);
+        assert!(error.causes.is_empty());
+    }
+
+    #[test]
+    fn can_serialize_errors() {
+        let api_error: ApiError = serde_json::from_value(json!({
+            "display_message": null,
+            "error_code": "INVALID_API_KEYS",
+            "error_message": "invalid client_id or secret provided",
+            "error_type": "INVALID_INPUT",
+            "request_id": "m8MDnv9okwxFNBV",
+            "documentation_url": null,
+            "suggested_action": null
+        }))
+        .unwrap();
+        let value = serde_json::to_value(Error::Api(api_error)).unwrap();
+        assert_eq!(value["kind"], "api");
+        assert_eq!(value["error_code"], "INVALID_API_KEYS");
+
+        let body = br#"{ "request_id": 42 }"#;
+        let de = &mut serde_json::Deserializer::from_slice(body);
+        let source = serde_path_to_error::deserialize::<_, ApiError>(de).unwrap_err();
+        let error = Error::Deserialize(DeserializeError::new(source, body));
+        let value = serde_json::to_value(error).unwrap();
+        assert_eq!(value["kind"], "deserialize");
+        assert!(value.get("body").is_none());
+    }
+
+    #[test]
+    fn suggests_http_status() {
+        let error_type: ErrorType = serde_json::from_value(json!("RATE_LIMIT_EXCEEDED")).unwrap();
+        assert_eq!(error_type.suggested_http_status(), 429);
+        assert_eq!(ErrorType::InvalidInput.suggested_http_status(), 400);
+        assert_eq!(ErrorType::ApiError.suggested_http_status(), 502);
+        assert_eq!(ErrorType::Unknown.suggested_http_status(), 500);
+    }
+
+    #[test]
+    fn user_message_prefers_display_message() {
+        let error: ApiError = serde_json::from_value(json!({
+            "display_message": "The credentials were not correct. Please try again.",
+            "error_code": "INVALID_CREDENTIALS",
+            "error_message": "the provided credentials were not correct",
+            "error_type": "ITEM_ERROR",
+            "request_id": "m8MDnv9okwxFNBV"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            error.user_message(),
+            "The credentials were not correct. Please try again."
+        );
+    }
+}
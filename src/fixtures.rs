@@ -0,0 +1,92 @@
+//! Canonical Plaid payloads and helpers shared by the tests.
+//!
+//! Tests start from these fixtures and only override the fields they are
+//! about, e.g. `value["balances"]["current"] = json!(null)`.
+
+use serde_json::{json, Value};
+use wiremock::MockServer;
+
+use crate::{Client, Environment};
+
+/// A `Client` sending its requests to `server`.
+pub(crate) fn mock_client(server: &MockServer) -> Client {
+    Client::builder("client_id", "secret".to_string(), Environment::Sandbox)
+        .base_url(server.uri())
+        .build()
+}
+
+/// A healthy Item, as in the `item` of most responses.
+pub(crate) fn item() -> Value {
+    json!({
+        "item_id": "DWVAAPWq4RHGlEaNyGKRTAnPLaEmo8Cvq7na6",
+        "institution_id": "ins_109508",
+        "webhook": "https://www.genericwebhookurl.com/webhook",
+        "error": null,
+        "available_products": ["balance", "identity"],
+        "billed_products": ["auth", "transactions"],
+        "consent_expiration_time": null
+    })
+}
+
+/// A depository account.
+pub(crate) fn account() -> Value {
+    json!({
+        "account_id": "blgvvBlXw3cq5GMPwqB6s6q4dLKB9WcVqGDGo",
+        "balances": {
+            "available": 100,
+            "current": 110,
+            "limit": null,
+            "iso_currency_code": "USD",
+            "unofficial_currency_code": null
+        },
+        "mask": "0000",
+        "name": "Plaid Checking",
+        "official_name": "Plaid Gold Standard 0% Interest Checking",
+        "type": "depository",
+        "verification_status": null
+    })
+}
+
+/// The response to an `accounts` request, with `account` as its only account.
+pub(crate) fn accounts_response() -> Value {
+    json!({
+        "accounts": [account()],
+        "item": item(),
+        "request_id": "bkVE1BHWMAZ9Rnr"
+    })
+}
+
+/// A settled transaction, without merchant enrichment.
+pub(crate) fn transaction() -> Value {
+    json!({
+        "transaction_id": "lPNjeW1nR6CDn5okmGQ6hEpMo4lLNoSrzqDje",
+        "account_id": "BxBXxLj1m4HMXBm9WZZmCWVbPjX16EHwv99vp",
+        "amount": 2307.21,
+        "iso_currency_code": "USD",
+        "unofficial_currency_code": null,
+        "category": ["Shops", "Computers and Electronics"],
+        "category_id": "19013000",
+        "date": "2017-01-29",
+        "authorized_date": "2017-01-27",
+        "name": "Apple Store",
+        "merchant_name": "Apple",
+        "pending": false,
+        "account_owner": null
+    })
+}
+
+/// An institution, without its status.
+pub(crate) fn institution() -> Value {
+    json!({
+        "institution_id": "ins_109512",
+        "name": "Houndstooth Bank",
+        "products": ["auth", "balance", "identity", "transactions"],
+        "country_codes": ["US"],
+        "url": "https://www.houndstoothbank.com",
+        "primary_color": "#004966",
+        "logo": null,
+        "routing_numbers": ["011000138", "011200365"],
+        "oauth": false,
+        "status": null
+    })
+}
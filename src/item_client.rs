@@ -0,0 +1,175 @@
+//! A handle to a single Item.
+
+use chrono::NaiveDate;
+
+use crate::{
+    AccountsResponse, AuthRequestOptions, AuthResponse, BalanceRequestOptions, Client, Error,
+    IdentityRequestOptions, InvestmentsRefreshResponse, ItemGetResponse, ItemHealth,
+    ItemRemoveResponse, SyncError, Transaction, TransactionsGetResponse,
+    TransactionsRequestOptions, TransactionsSyncResponse, WebhookUpdateResponse,
+};
+
+/// A `Client` bound to the access token of an Item, obtained with
+/// `Client::item`.
+///
+/// Each method makes the `Client` request of the same name (e.g. `accounts`
+/// makes an `accounts` request, and `get` an `item_get` request) for the
+/// Item, without passing its access token every time.
+///
+/// ```no_run
+/// # async fn example(client: plaid::Client, public_token: &str) -> Result<(), plaid::Error> {
+/// let response = client.exchange_public_token(public_token).await?;
+/// let item = client.item(response.access_token);
+///
+/// let accounts = item.accounts().await?;
+/// let auth = item.auth(Default::default()).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ItemClient<'a> {
+    client: &'a Client,
+    access_token: String,
+}
+
+impl<'a> ItemClient<'a> {
+    pub(crate) fn new(client: &'a Client, access_token: String) -> Self {
+        ItemClient {
+            client,
+            access_token,
+        }
+    }
+
+    /// The `Client` making the requests.
+    pub fn client(&self) -> &'a Client {
+        self.client
+    }
+
+    /// The access token of the Item.
+    pub fn access_token(&self) -> &str {
+        &self.access_token
+    }
+
+    /// See `Client::accounts`.
+    pub async fn accounts(&self) -> Result<AccountsResponse, Error> {
+        self.client.accounts(&self.access_token).await
+    }
+
+    /// See `Client::balance`.
+    pub async fn balance(&self, options: BalanceRequestOptions) -> Result<AccountsResponse, Error> {
+        self.client.balance(&self.access_token, options).await
+    }
+
+    /// See `Client::auth`.
+    pub async fn auth(&self, options: AuthRequestOptions) -> Result<AuthResponse, Error> {
+        self.client.auth(&self.access_token, options).await
+    }
+
+    /// See `Client::identity`.
+    pub async fn identity(
+        &self,
+        options: IdentityRequestOptions,
+    ) -> Result<AccountsResponse, Error> {
+        self.client.identity(&self.access_token, options).await
+    }
+
+    /// See `Client::investments_refresh`.
+    pub async fn investments_refresh(&self) -> Result<InvestmentsRefreshResponse, Error> {
+        self.client.investments_refresh(&self.access_token).await
+    }
+
+    /// See `Client::transactions`.
+    pub async fn transactions(
+        &self,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        options: TransactionsRequestOptions,
+    ) -> Result<TransactionsGetResponse, Error> {
+        self.client
+            .transactions(&self.access_token, start_date, end_date, options)
+            .await
+    }
+
+    /// See `Client::transactions_sync`.
+    pub async fn transactions_sync(
+        &self,
+        cursor: Option<&str>,
+    ) -> Result<TransactionsSyncResponse, SyncError> {
+        self.client
+            .transactions_sync(&self.access_token, cursor)
+            .await
+    }
+
+    /// See `Client::transactions_all`.
+    pub async fn transactions_all(&self) -> Result<Vec<Transaction>, Error> {
+        self.client.transactions_all(&self.access_token).await
+    }
+
+    /// See `Client::item_get`.
+    pub async fn get(&self) -> Result<ItemGetResponse, Error> {
+        self.client.item_get(&self.access_token).await
+    }
+
+    /// See `Client::item_health`.
+    pub async fn health(&self) -> Result<ItemHealth, Error> {
+        self.client.item_health(&self.access_token).await
+    }
+
+    /// See `Client::update_webhook`.
+    pub async fn update_webhook(&self, webhook_url: &str) -> Result<WebhookUpdateResponse, Error> {
+        self.client
+            .update_webhook(&self.access_token, webhook_url)
+            .await
+    }
+
+    /// See `Client::item_remove`. The access token is no longer valid
+    /// afterwards, hence this consumes the `ItemClient`.
+    pub async fn remove(self) -> Result<ItemRemoveResponse, Error> {
+        self.client.item_remove(&self.access_token).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::fixtures::{self, mock_client};
+
+    const ACCESS_TOKEN: &str = "access-sandbox-de3ce8ef-33f8-452c-a685-8671031fc0f6";
+
+    #[tokio::test]
+    async fn passes_access_token_of_item() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/item/webhook/update"))
+            .and(body_partial_json(json!({ "access_token": ACCESS_TOKEN })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "item": fixtures::item(),
+                "request_id": "vYK11LNTfRoAMbj"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/item/remove"))
+            .and(body_partial_json(json!({ "access_token": ACCESS_TOKEN })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "request_id": "m8MDnv9okwxFNBV" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let item = client.item(ACCESS_TOKEN);
+        assert_eq!(item.access_token(), ACCESS_TOKEN);
+        item.update_webhook("https://plaid.com/example/hook")
+            .await
+            .unwrap();
+        let response = item.remove().await.unwrap();
+        assert_eq!(response.request_id, "m8MDnv9okwxFNBV");
+    }
+}
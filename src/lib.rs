@@ -27,34 +27,193 @@
 //! }
 //! ```
 
+use std::collections::{HashMap, HashSet};
 use std::env;
-use std::time::Duration;
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use arc_swap::ArcSwap;
+use futures_util::stream::{self, StreamExt};
 use reqwest::{Client as ReqwestClient, StatusCode};
+use serde::de::DeserializeOwned;
 use serde_json::json;
 
+pub use self::api::PlaidApi;
+pub use self::cache::{AccountsCache, CachedAccounts};
 pub use self::error::*;
+pub use self::item_client::ItemClient;
+pub use self::metrics::Metrics;
 pub use self::types::*;
 pub use self::webhook::*;
 
+mod api;
+mod cache;
 mod error;
+#[cfg(test)]
+mod fixtures;
+mod item_client;
+mod metrics;
 mod types;
+#[cfg(feature = "webhook-verification")]
+pub mod verification;
 
 // TODO: add `Error` type and improve error handling
 // TODO: make `AccessToken` type to differentiate from `PublicToken` etc.
 // TODO: avoid allocation for all URLs
 // TODO: determine public (& private) organization of modules/types etc.
-// TODO: allow overriding `client_id` etc. for requests?
 
 /// **[Plaid](https://plaid.com/docs) API client**.
 ///
 /// See official documentation at: [https://plaid.com/docs](https://plaid.com/docs).
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Client {
-    client_id: String,
-    secret: Secret,
+    credentials: Arc<ArcSwap<Credentials>>,
+    environment: Environment,
     url: String,
     client: ReqwestClient,
+    max_response_size: Option<usize>,
+    metrics: Option<Arc<dyn Metrics>>,
+}
+
+/// A builder for a `Client`.
+///
+/// Created with `Client::builder`.
+///
+/// Plaid serves every country, including the European ones in
+/// `SupportedCountry`, from the same per-environment hosts, so there is no
+/// region to configure: the client targets `https://{environment}.plaid.com`
+/// unless `base_url` overrides it.
+#[derive(Clone)]
+pub struct ClientBuilder {
+    credentials: Credentials,
+    environment: Environment,
+    base_url: Option<String>,
+    root_certificates: Vec<reqwest::Certificate>,
+    tls_built_in_root_certs: bool,
+    max_response_size: Option<usize>,
+    metrics: Option<Arc<dyn Metrics>>,
+}
+
+impl fmt::Debug for ClientBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("credentials", &self.credentials)
+            .field("environment", &self.environment)
+            .field("base_url", &self.base_url)
+            .field("root_certificates", &self.root_certificates)
+            .field("tls_built_in_root_certs", &self.tls_built_in_root_certs)
+            .field("max_response_size", &self.max_response_size)
+            .field("metrics", &self.metrics.is_some())
+            .finish()
+    }
+}
+
+impl ClientBuilder {
+    /// Sets the base URL requests are sent to, instead of the one of the
+    /// `Environment`, e.g. to go through a proxy or to target a mock server.
+    pub fn base_url<S>(mut self, base_url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Limits the size of response bodies to `max_response_size` bytes.
+    ///
+    /// Reading a larger body is aborted with `Error::ResponseTooLarge`, so that
+    /// a misbehaving upstream cannot exhaust memory. The PDFs returned by
+    /// `asset_report_pdf` can legitimately be large and are not limited.
+    pub fn max_response_size(mut self, max_response_size: usize) -> Self {
+        self.max_response_size = Some(max_response_size);
+        self
+    }
+
+    /// Reports the outcome and latency of every request to `metrics`.
+    pub fn metrics<M>(mut self, metrics: M) -> Self
+    where
+        M: Metrics + 'static,
+    {
+        self.metrics = Some(Arc::new(metrics));
+        self
+    }
+
+    /// Adds a certificate to the trusted roots of the client, e.g. the one of
+    /// a private certificate authority.
+    ///
+    /// Like every request, the TLS connection goes through the proxy set in
+    /// the `HTTPS_PROXY` or `ALL_PROXY` environment variable, if any. A proxy
+    /// that inspects TLS traffic presents its own certificates instead of
+    /// Plaid's, so its certificate authority must be added here.
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Controls whether the client trusts the root certificates built into
+    /// the TLS backend. Defaults to `true`.
+    ///
+    /// Disable it to only trust certificates added with
+    /// `add_root_certificate`, e.g. to pin the certificate authority of Plaid
+    /// or of a TLS-inspecting proxy.
+    pub fn tls_built_in_root_certs(mut self, enabled: bool) -> Self {
+        self.tls_built_in_root_certs = enabled;
+        self
+    }
+
+    /// Builds the `Client`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be created, e.g. because the TLS
+    /// backend rejects a certificate added with `add_root_certificate`. Use
+    /// `try_build` to handle this as an error instead.
+    pub fn build(self) -> Client {
+        self.try_build().expect("could not create Reqwest client")
+    }
+
+    /// Builds the `Client`, failing if the HTTP client cannot be created.
+    pub fn try_build(self) -> Result<Client, reqwest::Error> {
+        let environment = self.environment;
+        let url = self
+            .base_url
+            .map(|url| url.trim_end_matches('/').to_string())
+            .unwrap_or_else(|| format!("https://{}.plaid.com", environment));
+
+        let client = self
+            .root_certificates
+            .into_iter()
+            .fold(ReqwestClient::builder(), |builder, certificate| {
+                builder.add_root_certificate(certificate)
+            })
+            .tls_built_in_root_certs(self.tls_built_in_root_certs)
+            .connect_timeout(Duration::from_secs(30))
+            .build()?;
+
+        Ok(Client {
+            credentials: Arc::new(ArcSwap::from_pointee(self.credentials)),
+            environment,
+            url,
+            client,
+            max_response_size: self.max_response_size,
+            metrics: self.metrics,
+        })
+    }
+}
+
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("credentials", &self.credentials)
+            .field("environment", &self.environment)
+            .field("url", &self.url)
+            .field("client", &self.client)
+            .field("max_response_size", &self.max_response_size)
+            .field("metrics", &self.metrics.is_some())
+            .finish()
+    }
 }
 
 impl Client {
@@ -64,14 +223,23 @@ impl Client {
         C: Into<String>,
         S: Into<Secret>,
     {
-        Client {
-            client_id: client_id.into(),
-            secret: secret.into(),
-            url: format!("https://{}.plaid.com", environment),
-            client: ReqwestClient::builder()
-                .connect_timeout(Duration::from_secs(30))
-                .build()
-                .expect("could not create Reqwest client"),
+        Client::builder(client_id, secret, environment).build()
+    }
+
+    /// Creates a `ClientBuilder` to configure a new `Client`.
+    pub fn builder<C, S>(client_id: C, secret: S, environment: Environment) -> ClientBuilder
+    where
+        C: Into<String>,
+        S: Into<Secret>,
+    {
+        ClientBuilder {
+            credentials: Credentials::new(client_id, secret),
+            environment,
+            base_url: None,
+            root_certificates: Vec::new(),
+            tls_built_in_root_certs: true,
+            max_response_size: None,
+            metrics: None,
         }
     }
 
@@ -80,14 +248,80 @@ impl Client {
     /// - `PLAID_SECRET`
     /// - `PLAID_ENVIRONMENT`
     pub fn from_env() -> Result<Client, Box<dyn std::error::Error>> {
+        Ok(Client::try_from_env()?)
+    }
+
+    /// Creates a new `Client` from the same environment variables as
+    /// `from_env`, reporting which one is missing or invalid.
+    pub fn try_from_env() -> Result<Client, FromEnvError> {
+        Client::from_env_prefixed("PLAID")
+    }
+
+    /// Creates a new `Client` from environment variables with a custom
+    /// prefix, e.g. `ACME_CLIENT_ID`, `ACME_SECRET` and `ACME_ENVIRONMENT`
+    /// for the prefix `"ACME"`.
+    ///
+    /// Useful to configure several clients for separate Plaid accounts in the
+    /// same process.
+    pub fn from_env_prefixed(prefix: &str) -> Result<Client, FromEnvError> {
         let client = Client::new(
-            env::var("PLAID_CLIENT_ID")?,
-            env::var("PLAID_SECRET")?,
-            env::var("PLAID_ENVIRONMENT")?.parse()?,
+            env_var(&format!("{}_CLIENT_ID", prefix))?,
+            env_var(&format!("{}_SECRET", prefix))?,
+            env_var(&format!("{}_ENVIRONMENT", prefix))?
+                .parse()
+                .map_err(FromEnvError::InvalidEnvironment)?,
         );
         Ok(client)
     }
 
+    /// The underlying HTTP client used to make requests.
+    ///
+    /// Exposed for observability, e.g. to attach instrumentation or to inspect
+    /// the connection pool; requests to the Plaid API should be made through
+    /// the methods on `Client`.
+    pub fn http_client(&self) -> &ReqwestClient {
+        &self.client
+    }
+
+    /// Replaces the secret used to authenticate requests.
+    ///
+    /// Requests made after this call use the new secret; requests already in
+    /// flight are unaffected. The secret is shared between a `Client` and its
+    /// clones, so rotating it on one rotates it on all of them, which allows
+    /// rotating credentials without reconstructing long-lived clients.
+    pub fn rotate_secret<S>(&self, secret: S)
+    where
+        S: Into<Secret>,
+    {
+        let secret = secret.into();
+        self.credentials.rcu(|credentials| Credentials {
+            client_id: credentials.client_id.clone(),
+            secret: secret.clone(),
+        });
+    }
+
+    /// Returns a `Client` that authenticates requests with the given
+    /// `Credentials`, e.g. those of another tenant.
+    ///
+    /// The returned client shares this client's HTTP connection pool, which
+    /// makes it cheap to create per request. Its credentials are independent:
+    /// rotating the secret of one does not affect the other.
+    pub fn with_credentials(&self, credentials: Credentials) -> Client {
+        Client {
+            credentials: Arc::new(ArcSwap::from_pointee(credentials)),
+            environment: self.environment,
+            url: self.url.clone(),
+            client: self.client.clone(),
+            max_response_size: self.max_response_size,
+            metrics: self.metrics.clone(),
+        }
+    }
+
+    /// The `Environment` this client makes requests against.
+    pub fn environment(&self) -> Environment {
+        self.environment
+    }
+
     /// Create a test Item
     ///
     /// [/sandbox/public_token/create]
@@ -107,22 +341,100 @@ impl Client {
         &self,
         request: &SandboxCreatePublicTokenRequest,
     ) -> Result<SandboxCreatePublicTokenResponse, Error> {
-        // TODO: figure out a better way to do this...
-        let mut body = json!(request);
-        body["client_id"] = json!(&self.client_id);
-        body["secret"] = json!(&self.secret);
+        let body = json!(request);
 
-        let response = self
-            .client
-            .post(&format!("{}/sandbox/public_token/create", self.url))
-            .json(&body)
-            .send()
-            .await?;
+        self.post("/sandbox/public_token/create", body).await
+    }
+
+    /// Set verification status for Sandbox account
+    ///
+    /// [/sandbox/item/set_verification_status]
+    ///
+    /// The [/sandbox/item/set_verification_status] endpoint can be used to
+    /// change the verification status of an Item in the Sandbox in order to
+    /// simulate the Automated Micro-deposit flow.
+    ///
+    /// *Note*: Only `VerificationStatus::AutomaticallyVerified` and
+    /// `VerificationStatus::VerificationExpired` are accepted.
+    ///
+    /// [/sandbox/item/set_verification_status]: https://plaid.com/docs/api/sandbox/#sandboxitemset_verification_status
+    pub async fn sandbox_item_set_verification_status(
+        &self,
+        access_token: &str,
+        account_id: &str,
+        verification_status: VerificationStatus,
+    ) -> Result<SandboxItemSetVerificationStatusResponse, Error> {
+        // TODO: make this strongly typed?
+        let body = json!({
+            "access_token": access_token,
+            "account_id": account_id,
+            "verification_status": verification_status,
+        });
+
+        self.post("/sandbox/item/set_verification_status", body)
+            .await
+    }
+
+    /// Set verification status for the first verifiable Sandbox account
+    ///
+    /// Retrieves the Item's accounts and calls
+    /// [`sandbox_item_set_verification_status`] for the first account that has
+    /// a `verification_status`, i.e. that was added via micro-deposits.
+    ///
+    /// Fails with `Error::NoVerifiableAccount` if the Item has no such
+    /// account.
+    ///
+    /// [`sandbox_item_set_verification_status`]: Client::sandbox_item_set_verification_status
+    pub async fn sandbox_verify_first_account(
+        &self,
+        access_token: &str,
+        verification_status: VerificationStatus,
+    ) -> Result<SandboxItemSetVerificationStatusResponse, Error> {
+        let accounts = self.accounts(access_token).await?.accounts;
+        let account = accounts
+            .iter()
+            .find(|a| a.verification_status.is_some())
+            .ok_or(Error::NoVerifiableAccount)?;
+
+        self.sandbox_item_set_verification_status(
+            access_token,
+            &account.account_id,
+            verification_status,
+        )
+        .await
+    }
 
-        match response.status() {
-            StatusCode::OK => Ok(response.json().await?),
-            _ => Err(Error::Api(response.json().await?)),
+    /// Fire a test webhook
+    ///
+    /// [/sandbox/item/fire_webhook]
+    ///
+    /// The [/sandbox/item/fire_webhook] endpoint is used to test that code
+    /// correctly handles webhooks, by firing the webhook of the given
+    /// `webhook_type` (e.g. `"ITEM"`) and `webhook_code` (e.g.
+    /// `"LOGIN_REPAIRED"`) to the webhook URL of the Item. Without a
+    /// `webhook_type`, Plaid picks the type of the given code.
+    ///
+    /// *Note*: `WEBHOOK_UPDATE_ACKNOWLEDGED` cannot be fired this way, and
+    /// Plaid accepts no new webhook URL here. In the Sandbox,
+    /// `update_webhook` fires it to the given URL instead.
+    ///
+    /// [/sandbox/item/fire_webhook]: https://plaid.com/docs/api/sandbox/#sandboxitemfire_webhook
+    pub async fn sandbox_item_fire_webhook(
+        &self,
+        access_token: &str,
+        webhook_type: Option<&str>,
+        webhook_code: &str,
+    ) -> Result<SandboxItemFireWebhookResponse, Error> {
+        // TODO: make this strongly typed?
+        let mut body = json!({
+            "access_token": access_token,
+            "webhook_code": webhook_code,
+        });
+        if let Some(webhook_type) = webhook_type {
+            body["webhook_type"] = json!(webhook_type);
         }
+
+        self.post("/sandbox/item/fire_webhook", body).await
     }
 
     /// Create Link Token
@@ -138,6 +450,10 @@ impl Client {
     /// initialize other Link flows, such as the update mode flow for tokens
     /// with expired credentials, or the Payment Initiation (Europe) flow.
     ///
+    /// In Production, fails with `Error::BillingNotAcknowledged` without
+    /// sending the request if it has a `billing_advisory` that was not
+    /// acknowledged with `acknowledge_billing`.
+    ///
     /// [/link/token/create]: https://plaid.com/docs/api/tokens/#linktokencreate
     /// [/item/public_token/exchange]: https://plaid.com/docs/api/tokens/#itempublic_tokenexchange
     /// [main Link flow]: https://plaid.com/docs/link/#link-flow
@@ -145,22 +461,41 @@ impl Client {
         &self,
         request: &CreateLinkTokenRequest,
     ) -> Result<CreateLinkTokenResponse, Error> {
-        // TODO: figure out a better way to do this...
-        let mut body = json!(request);
-        body["client_id"] = json!(&self.client_id);
-        body["secret"] = json!(&self.secret);
+        if let Some(advisory) = request.billing_advisory(self.environment) {
+            return Err(Error::BillingNotAcknowledged(advisory));
+        }
 
-        let response = self
-            .client
-            .post(&format!("{}/link/token/create", self.url))
-            .json(&body)
-            .send()
-            .await?;
+        let body = json!(request);
 
-        match response.status() {
-            StatusCode::OK => Ok(response.json().await?),
-            _ => Err(Error::Api(response.json().await?)),
-        }
+        self.post("/link/token/create", body).await
+    }
+
+    /// Create Link Token for update mode
+    ///
+    /// Creates a `link_token` that launches Link in update mode for the Item
+    /// of `access_token`, which lets the user re-authenticate it, e.g. to fix
+    /// an `ITEM_LOGIN_REQUIRED` error. See
+    /// `CreateLinkTokenRequest::update_mode`.
+    pub async fn create_update_link_token<N>(
+        &self,
+        access_token: &str,
+        client_name: N,
+        language: SupportedLanguage,
+        country_codes: Vec<SupportedCountry>,
+        user: EndUser,
+    ) -> Result<CreateLinkTokenResponse, Error>
+    where
+        N: Into<String>,
+    {
+        let request = CreateLinkTokenRequest::update_mode(
+            access_token,
+            client_name,
+            language,
+            country_codes,
+            user,
+        );
+
+        self.create_link_token(&request).await
     }
 
     /// Exchange a public token for an access token
@@ -184,22 +519,10 @@ impl Client {
     ) -> Result<ExchangePublicTokenResponse, Error> {
         // TODO: make this strongly typed?
         let body = json!({
-            "client_id": &self.client_id,
-            "secret": &self.secret,
             "public_token": public_token,
         });
 
-        let response = self
-            .client
-            .post(&format!("{}/item/public_token/exchange", self.url))
-            .json(&body)
-            .send()
-            .await?;
-
-        match response.status() {
-            StatusCode::OK => Ok(response.json().await?),
-            _ => Err(Error::Api(response.json().await?)),
-        }
+        self.post("/item/public_token/exchange", body).await
     }
 
     /// Create processor token
@@ -227,24 +550,166 @@ impl Client {
     ) -> Result<CreateProcessorTokenResponse, Error> {
         // TODO: make this strongly typed?
         let body = json!({
-            "client_id": &self.client_id,
-            "secret": &self.secret,
             "access_token": access_token,
             "account_id": account_id,
             "processor": processor,
         });
 
-        let response = self
-            .client
-            .post(&format!("{}/processor/token/create", self.url))
-            .json(&body)
-            .send()
-            .await?;
+        self.post("/processor/token/create", body).await
+    }
+
+    /// Get details of an institution
+    ///
+    /// [/institutions/get_by_id]
+    ///
+    /// Returns a JSON response containing details on a specified financial
+    /// institution currently supported by Plaid.
+    ///
+    /// [/institutions/get_by_id]: https://plaid.com/docs/api/institutions/#institutionsget_by_id
+    pub async fn institution(
+        &self,
+        institution_id: &str,
+        country_codes: &[SupportedCountry],
+        options: InstitutionRequestOptions,
+    ) -> Result<InstitutionResponse, Error> {
+        // TODO: make this strongly typed?
+        let body = json!({
+            "institution_id": institution_id,
+            "country_codes": country_codes,
+            "options": options,
+        });
+
+        self.post("/institutions/get_by_id", body).await
+    }
+
+    /// Get details of all supported institutions
+    ///
+    /// [/institutions/get]
+    ///
+    /// Returns a JSON response containing details on all financial
+    /// institutions currently supported by Plaid. Because Plaid supports
+    /// thousands of institutions, results are paginated with `count` (at most
+    /// 500) and `offset`.
+    ///
+    /// Only institutions supporting all of the given `products` are returned;
+    /// pass an empty slice to not filter by product.
+    ///
+    /// [/institutions/get]: https://plaid.com/docs/api/institutions/#institutionsget
+    pub async fn institutions(
+        &self,
+        count: u32,
+        offset: u32,
+        country_codes: &[SupportedCountry],
+        products: &[Product],
+    ) -> Result<InstitutionsGetResponse, Error> {
+        // TODO: make this strongly typed?
+        let body = json!({
+            "count": count,
+            "offset": offset,
+            "country_codes": country_codes,
+            "options": {
+                // like `serde_utils::default_on_null`, Plaid rejects `[]`
+                "products": if products.is_empty() { None } else { Some(products) },
+            },
+        });
+
+        self.post("/institutions/get", body).await
+    }
+
+    /// Retrieves every institution supported by Plaid.
+    ///
+    /// Pages through [/institutions/get] 500 institutions at a time. Plaid
+    /// offers no parameter to pin the order of results, so if it shifts
+    /// between pages an institution can appear twice; such repeats are
+    /// dropped, and the returned institutions have distinct
+    /// `institution_id`s.
+    ///
+    /// [/institutions/get]: https://plaid.com/docs/api/institutions/#institutionsget
+    pub async fn institutions_all(
+        &self,
+        country_codes: &[SupportedCountry],
+        products: &[Product],
+    ) -> Result<Vec<Institution>, Error> {
+        const PAGE_SIZE: u32 = 500;
+
+        let mut institutions = Vec::new();
+        let mut seen = HashSet::new();
+        let mut offset = 0;
+        loop {
+            let response = self
+                .institutions(PAGE_SIZE, offset, country_codes, products)
+                .await?;
+            let page_len = response.institutions.len() as u32;
+
+            for institution in response.institutions {
+                if seen.insert(institution.institution_id.clone()) {
+                    institutions.push(institution);
+                }
+            }
+
+            offset += page_len;
+            if page_len == 0 || offset >= response.total {
+                break;
+            }
+        }
+        Ok(institutions)
+    }
+
+    /// Search institutions
+    ///
+    /// [/institutions/search]
+    ///
+    /// Returns a JSON response containing details for institutions that match
+    /// the query parameters, up to a maximum of ten institutions per query.
+    ///
+    /// Only institutions supporting all of the given `products` are returned;
+    /// pass an empty slice to not filter by product.
+    ///
+    /// [/institutions/search]: https://plaid.com/docs/api/institutions/#institutionssearch
+    pub async fn institutions_search(
+        &self,
+        query: &str,
+        country_codes: &[SupportedCountry],
+        products: &[Product],
+    ) -> Result<InstitutionsSearchResponse, Error> {
+        // TODO: make this strongly typed?
+        let body = json!({
+            "query": query,
+            "country_codes": country_codes,
+            // like `serde_utils::default_on_null`, Plaid rejects `[]`
+            "products": if products.is_empty() { None } else { Some(products) },
+        });
 
-        match response.status() {
-            StatusCode::OK => Ok(response.json().await?),
-            _ => Err(Error::Api(response.json().await?)),
+        self.post("/institutions/search", body).await
+    }
+
+    /// Retrieves the logo of an institution, falling back to the favicon of
+    /// its website.
+    ///
+    /// Returns the decoded `Institution::logo` if present, otherwise fetches
+    /// `/favicon.ico` from `Institution::url` with the client's HTTP pool.
+    /// Returns `None` if the institution has neither a logo nor a valid URL.
+    pub async fn institution_logo_or_favicon(
+        &self,
+        institution: &Institution,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        if let Some(logo) = institution.decoded_logo() {
+            return Ok(Some(logo));
         }
+
+        let favicon_url = match institution
+            .url
+            .as_deref()
+            .and_then(|url| reqwest::Url::parse(url).ok())
+            .and_then(|url| url.join("/favicon.ico").ok())
+        {
+            Some(favicon_url) => favicon_url,
+            None => return Ok(None),
+        };
+
+        let response = self.client.get(favicon_url).send().await?;
+        let bytes = read_body(response.error_for_status()?, self.max_response_size).await?;
+        Ok(Some(bytes))
     }
 
     /// Retrieve accounts
@@ -255,26 +720,77 @@ impl Client {
     /// nullable. Plaid will only return active bank accounts, i.e. accounts
     /// that are not closed and are capable of carrying a balance.
     ///
+    /// An Item in an error state may still return its accounts, with the
+    /// error reported in `item.error` of the `AccountsResponse`; only error
+    /// responses from the API are returned as `Error::Api`.
+    ///
     /// [/accounts/get]: https://plaid.com/docs/api/accounts/#accountsget
     pub async fn accounts(&self, access_token: &str) -> Result<AccountsResponse, Error> {
+        self.accounts_with_options(access_token, Default::default())
+            .await
+    }
+
+    /// Retrieve accounts
+    ///
+    /// [/accounts/get]
+    ///
+    /// Like `accounts`, but only retrieves the accounts selected by `options`.
+    ///
+    /// [/accounts/get]: https://plaid.com/docs/api/accounts/#accountsget
+    pub async fn accounts_with_options(
+        &self,
+        access_token: &str,
+        options: AccountsGetRequestOptions,
+    ) -> Result<AccountsResponse, Error> {
         // TODO: make this strongly typed?
         let body = json!({
-            "client_id": &self.client_id,
-            "secret": &self.secret,
             "access_token": access_token,
+            "options": options,
         });
 
-        let response = self
-            .client
-            .post(&format!("{}/accounts/get", self.url))
-            .json(&body)
-            .send()
-            .await?;
+        self.post("/accounts/get", body).await
+    }
 
-        match response.status() {
-            StatusCode::OK => Ok(response.json().await?),
-            _ => Err(Error::Api(response.json().await?)),
-        }
+    /// Retrieve accounts
+    ///
+    /// [/accounts/get]
+    ///
+    /// Like `accounts`, but also returns the raw JSON of the response, e.g. to
+    /// find fields that `AccountsResponse` does not model. Such fields are
+    /// rejected with the `strict` feature enabled, so it should be disabled
+    /// when using this method to diagnose schema mismatches.
+    ///
+    /// [/accounts/get]: https://plaid.com/docs/api/accounts/#accountsget
+    pub async fn accounts_raw(
+        &self,
+        access_token: &str,
+    ) -> Result<(AccountsResponse, serde_json::Value), Error> {
+        // TODO: make this strongly typed?
+        let body = json!({
+            "access_token": access_token,
+        });
+
+        self.post_raw("/accounts/get", body).await
+    }
+
+    /// Retrieves the accounts of several Items, making at most `concurrency`
+    /// requests at a time.
+    ///
+    /// Each result is paired with its access token, in the order of `tokens`.
+    /// A `concurrency` of `0` is treated as `1`.
+    pub async fn accounts_multi(
+        &self,
+        tokens: &[String],
+        concurrency: usize,
+    ) -> Vec<(String, Result<AccountsResponse, Error>)> {
+        stream::iter(tokens.iter().cloned())
+            .map(|token| async move {
+                let result = self.accounts(&token).await;
+                (token, result)
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
     }
 
     /// Fetch real-time balance data
@@ -297,23 +813,11 @@ impl Client {
     ) -> Result<AccountsResponse, Error> {
         // TODO: make this strongly typed?
         let body = json!({
-            "client_id": &self.client_id,
-            "secret": &self.secret,
             "access_token": access_token,
             "options": options,
         });
 
-        let response = self
-            .client
-            .post(&format!("{}/accounts/balance/get", self.url))
-            .json(&body)
-            .send()
-            .await?;
-
-        match response.status() {
-            StatusCode::OK => Ok(response.json().await?),
-            _ => Err(Error::Api(response.json().await?)),
-        }
+        self.post("/accounts/balance/get", body).await
     }
 
     /// Fetch auth data
@@ -338,23 +842,11 @@ impl Client {
     ) -> Result<AuthResponse, Error> {
         // TODO: make this strongly typed?
         let body = json!({
-            "client_id": &self.client_id,
-            "secret": &self.secret,
             "access_token": access_token,
             "options": options,
         });
 
-        let response = self
-            .client
-            .post(&format!("{}/auth/get", self.url))
-            .json(&body)
-            .send()
-            .await?;
-
-        match response.status() {
-            StatusCode::OK => Ok(response.json().await?),
-            _ => Err(Error::Api(response.json().await?)),
-        }
+        self.post("/auth/get", body).await
     }
 
     /// Fetch identity data
@@ -372,71 +864,596 @@ impl Client {
     /// data.
     ///
     /// [/identity/get]: https://plaid.com/docs/api/products/#identityget
-    pub async fn identity(&self, access_token: &str) -> Result<AccountsResponse, Error> {
+    pub async fn identity(
+        &self,
+        access_token: &str,
+        options: IdentityRequestOptions,
+    ) -> Result<AccountsResponse, Error> {
         // TODO: make this strongly typed?
         let body = json!({
-            "client_id": &self.client_id,
-            "secret": &self.secret,
             "access_token": access_token,
+            "options": options,
         });
 
-        let response = self
-            .client
-            .post(&format!("{}/identity/get", self.url))
-            .json(&body)
-            .send()
-            .await?;
-
-        match response.status() {
-            StatusCode::OK => Ok(response.json().await?),
-            _ => Err(Error::Api(response.json().await?)),
-        }
+        self.post("/identity/get", body).await
     }
 
-    /// Update webhook callback URL
+    /// Refresh investment data
     ///
-    /// [/item/webhook/update]
+    /// [/investments/refresh]
     ///
-    /// Update the webhook URL associated with an Item. This request triggers a
-    /// WEBHOOK_UPDATE_ACKNOWLEDGED webhook to the newly specified webhook URL.
+    /// Initiates on-demand extraction to fetch the newest investment holdings
+    /// and transactions for an Item. This on-demand extraction takes place in
+    /// addition to the periodic extractions that automatically occur one or
+    /// more times per day for any Investments-enabled Item. If changes to
+    /// investments are discovered after calling [/investments/refresh], Plaid
+    /// will fire webhooks: [`HOLDINGS: DEFAULT_UPDATE`] if any new holdings are
+    /// detected, and [`INVESTMENTS_TRANSACTIONS: DEFAULT_UPDATE`] if any new
+    /// investment transactions are detected.
     ///
-    /// [/item/webhook/update]: https://plaid.com/docs/api/items/#itemwebhookupdate
-    pub async fn update_webhook(
+    /// *Note*: [/investments/refresh] is offered as an add-on to Investments
+    /// and has a separate fee; each call is billed. The endpoint is also rate
+    /// limited per Item, so it should only be used to satisfy an explicit user
+    /// request rather than polled.
+    ///
+    /// [/investments/refresh]: https://plaid.com/docs/api/products/investments/#investmentsrefresh
+    /// [`HOLDINGS: DEFAULT_UPDATE`]: https://plaid.com/docs/api/products/investments/#holdings-default_update
+    /// [`INVESTMENTS_TRANSACTIONS: DEFAULT_UPDATE`]: https://plaid.com/docs/api/products/investments/#investments_transactions-default_update
+    pub async fn investments_refresh(
         &self,
         access_token: &str,
-        webhook_url: &str,
-    ) -> Result<WebhookUpdateResponse, Error> {
+    ) -> Result<InvestmentsRefreshResponse, Error> {
         // TODO: make this strongly typed?
         let body = json!({
-            "client_id": &self.client_id,
-            "secret": &self.secret,
             "access_token": access_token,
-            "webhook": webhook_url
         });
 
-        let response = self
-            .client
-            .post(&format!("{}/item/webhook/update", self.url))
-            .json(&body)
-            .send()
-            .await?;
-
-        match response.status() {
-            StatusCode::OK => Ok(response.json().await?),
-            _ => Err(Error::Api(response.json().await?)),
-        }
+        self.post("/investments/refresh", body).await
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::error::Error as StdError;
+    /// Get transaction data
+    ///
+    /// [/transactions/get]
+    ///
+    /// The [/transactions/get] endpoint allows developers to receive user-
+    /// authorized transaction data for credit, depository, and some loan-type
+    /// accounts, between `start_date` and `end_date` (inclusive).
+    ///
+    /// Transactions are paginated; use `count` and `offset` in `options` to
+    /// fetch more than the first page, up to `total_transactions`.
+    ///
+    /// [/transactions/get]: https://plaid.com/docs/api/products/transactions/#transactionsget
+    pub async fn transactions(
+        &self,
+        access_token: &str,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        options: TransactionsRequestOptions,
+    ) -> Result<TransactionsGetResponse, Error> {
+        // TODO: make this strongly typed?
+        let body = json!({
+            "access_token": access_token,
+            "start_date": start_date.format("%Y-%m-%d").to_string(),
+            "end_date": end_date.format("%Y-%m-%d").to_string(),
+            "options": options,
+        });
 
-    use super::*;
+        self.post("/transactions/get", body).await
+    }
 
-    async fn client_from_env() -> Result<(Client, String), Box<dyn StdError>> {
-        let client_id = dotenv::var("PLAID_CLIENT_ID")?;
-        let secret = dotenv::var("PLAID_SECRET")?;
+    /// Get incremental transaction updates on an Item
+    ///
+    /// [/transactions/sync]
+    ///
+    /// The [/transactions/sync] endpoint returns item transactions as a set
+    /// of delta updates. Subsequent calls to the endpoint using the cursor
+    /// returned in the response will return new added, modified, and removed
+    /// transactions since the last call to the endpoint.
+    ///
+    /// Pass `None` as the `cursor` to fetch all historical transactions, and
+    /// keep requesting with `next_cursor` while `has_more` is `true`. Use
+    /// `TransactionsSyncResponse::apply_to` to apply each page to a local
+    /// store.
+    ///
+    /// If the Item's transactions change while paging, the cursor is
+    /// invalidated and `SyncError::CursorInvalidated` is returned; paging must
+    /// then restart as it describes.
+    ///
+    /// [/transactions/sync]: https://plaid.com/docs/api/products/transactions/#transactionssync
+    pub async fn transactions_sync(
+        &self,
+        access_token: &str,
+        cursor: Option<&str>,
+    ) -> Result<TransactionsSyncResponse, SyncError> {
+        // TODO: make this strongly typed?
+        let body = json!({
+            "access_token": access_token,
+            "cursor": cursor.unwrap_or_default(),
+        });
+
+        Ok(self.post("/transactions/sync", body).await?)
+    }
+
+    /// Retrieves all available transactions of an Item.
+    ///
+    /// Pages through [/transactions/sync] from the beginning of the Item's
+    /// history, so no date range is needed: every transaction Plaid has for
+    /// the Item is returned, up to 24 months. If the Item's transactions
+    /// change while paging, which is reported as
+    /// `SyncError::CursorInvalidated`, paging restarts from the beginning,
+    /// after a delay doubling from 250ms. After 3 restarts, the last such
+    /// error is returned.
+    ///
+    /// Transactions are returned newest first, like `transactions`.
+    ///
+    /// [/transactions/sync]: https://plaid.com/docs/api/products/transactions/#transactionssync
+    pub async fn transactions_all(&self, access_token: &str) -> Result<Vec<Transaction>, Error> {
+        const MAX_RESTARTS: u32 = 3;
+        const INITIAL_RESTART_DELAY: Duration = Duration::from_millis(250);
+
+        let mut store = HashMap::new();
+        let mut cursor: Option<String> = None;
+        let mut restarts = 0;
+        loop {
+            let response = match self
+                .transactions_sync(access_token, cursor.as_deref())
+                .await
+            {
+                Err(SyncError::CursorInvalidated(error)) => {
+                    if restarts == MAX_RESTARTS {
+                        return Err(Error::Api(error));
+                    }
+                    tokio::time::sleep(INITIAL_RESTART_DELAY * 2u32.pow(restarts)).await;
+                    restarts += 1;
+                    store.clear();
+                    cursor = None;
+                    continue;
+                }
+                result => result?,
+            };
+
+            response.apply_to(&mut store);
+            if !response.has_more {
+                break;
+            }
+            cursor = Some(response.next_cursor);
+        }
+
+        let mut transactions: Vec<Transaction> = store.into_values().collect();
+        transactions.sort_by_key(|transaction| std::cmp::Reverse(transaction.date));
+        Ok(transactions)
+    }
+
+    /// Create a transfer authorization
+    ///
+    /// [/transfer/authorization/create]
+    ///
+    /// Use the [/transfer/authorization/create] endpoint to determine
+    /// transfer failure risk. If the `decision` of the returned authorization
+    /// is `Declined`, its `decision_rationale` tells why.
+    ///
+    /// [/transfer/authorization/create]: https://plaid.com/docs/api/products/transfer/initiating-transfers/#transferauthorizationcreate
+    pub async fn transfer_authorization_create(
+        &self,
+        request: &TransferAuthorizationCreateRequest,
+    ) -> Result<TransferAuthorizationCreateResponse, Error> {
+        let body = json!(request);
+
+        self.post("/transfer/authorization/create", body).await
+    }
+
+    /// List transfers
+    ///
+    /// [/transfer/list]
+    ///
+    /// Use the [/transfer/list] endpoint to see a list of all your transfers
+    /// and their statuses. Results are paginated; use `count` and `offset` to
+    /// query for the next set of results.
+    ///
+    /// [/transfer/list]: https://plaid.com/docs/api/products/transfer/reading-transfers/#transferlist
+    pub async fn transfer_list(
+        &self,
+        request: &TransferListRequest,
+    ) -> Result<TransferListResponse, Error> {
+        let body = json!(request);
+
+        self.post("/transfer/list", body).await
+    }
+
+    /// List payments
+    ///
+    /// [/payment_initiation/payment/list]
+    ///
+    /// Lists Payment Initiation payments, most recently created first. Pass
+    /// the `next_cursor` of a response as `cursor` to fetch the next page.
+    ///
+    /// Default `count`: `10`, Maximum: `200`.
+    ///
+    /// [/payment_initiation/payment/list]: https://plaid.com/docs/api/products/payment-initiation/#payment_initiationpaymentlist
+    pub async fn payment_initiation_payment_list(
+        &self,
+        count: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<PaymentListResponse, Error> {
+        // TODO: make this strongly typed?
+        let body = json!({
+            "count": count,
+            "cursor": cursor,
+        });
+
+        self.post("/payment_initiation/payment/list", body).await
+    }
+
+    /// Create user
+    ///
+    /// [/user/create]
+    ///
+    /// This endpoint should be called for each of your end users before they
+    /// begin a Plaid Consumer Report flow. It returns a `user_token`, which is
+    /// used instead of an `access_token` by the Consumer Report endpoints and
+    /// can be set on a `CreateLinkTokenRequest`.
+    ///
+    /// `client_user_id` is a unique ID representing the end user, and
+    /// `consumer_report_user_identity` is required by the Consumer Report
+    /// products.
+    ///
+    /// [/user/create]: https://plaid.com/docs/api/users/#usercreate
+    pub async fn user_create(
+        &self,
+        client_user_id: &str,
+        consumer_report_user_identity: Option<&ConsumerReportUserIdentity>,
+    ) -> Result<UserCreateResponse, Error> {
+        // TODO: make this strongly typed?
+        let mut body = json!({
+            "client_user_id": client_user_id,
+        });
+        if let Some(identity) = consumer_report_user_identity {
+            body["consumer_report_user_identity"] = json!(identity);
+        }
+
+        self.post("/user/create", body).await
+    }
+
+    /// Create an Asset Report
+    ///
+    /// [/asset_report/create]
+    ///
+    /// The [/asset_report/create] endpoint initiates the process of creating
+    /// an Asset Report, which can then be retrieved by passing the
+    /// `asset_report_token` return value to the [/asset_report/get] or
+    /// [/asset_report/pdf/get] endpoints.
+    ///
+    /// The Asset Report takes some time to be created and is not available
+    /// immediately after calling [/asset_report/create]. When the Asset Report
+    /// is ready to be retrieved, Plaid will fire an `ASSETS: PRODUCT_READY`
+    /// webhook.
+    ///
+    /// [/asset_report/create]: https://plaid.com/docs/api/products/assets/#asset_reportcreate
+    /// [/asset_report/get]: https://plaid.com/docs/api/products/assets/#asset_reportget
+    /// [/asset_report/pdf/get]: https://plaid.com/docs/api/products/assets/#asset_reportpdfget
+    pub async fn asset_report_create(
+        &self,
+        request: &AssetReportCreateRequest,
+    ) -> Result<AssetReportCreateResponse, Error> {
+        let body = json!(request);
+
+        self.post("/asset_report/create", body).await
+    }
+
+    /// Retrieve an Asset Report
+    ///
+    /// [/asset_report/get]
+    ///
+    /// The [/asset_report/get] endpoint retrieves the Asset Report in JSON
+    /// format. Before calling [/asset_report/get], you must first create the
+    /// Asset Report using [/asset_report/create] and then wait for the
+    /// `ASSETS: PRODUCT_READY` webhook to fire, indicating that the Report is
+    /// ready to be retrieved.
+    ///
+    /// When `include_insights` is set, transactions in the report include the
+    /// insight fields of `AssetReportTransaction`, such as their merchant
+    /// name and category.
+    ///
+    /// [/asset_report/get]: https://plaid.com/docs/api/products/assets/#asset_reportget
+    /// [/asset_report/create]: https://plaid.com/docs/api/products/assets/#asset_reportcreate
+    pub async fn asset_report_get(
+        &self,
+        asset_report_token: &str,
+        include_insights: bool,
+    ) -> Result<AssetReportGetResponse, Error> {
+        // TODO: make this strongly typed?
+        let body = json!({
+            "asset_report_token": asset_report_token,
+            "include_insights": include_insights,
+        });
+
+        self.post("/asset_report/get", body).await
+    }
+
+    /// Retrieve a PDF Asset Report
+    ///
+    /// [/asset_report/pdf/get]
+    ///
+    /// The [/asset_report/pdf/get] endpoint retrieves the Asset Report in PDF
+    /// format. Before calling [/asset_report/pdf/get], you must first create
+    /// the Asset Report using [/asset_report/create] and then wait for the
+    /// `ASSETS: PRODUCT_READY` webhook to fire.
+    ///
+    /// Returns the bytes of the PDF. As reports can be large, `timeout`
+    /// overrides the timeout of this request only.
+    ///
+    /// [/asset_report/pdf/get]: https://plaid.com/docs/api/products/assets/#asset_reportpdfget
+    /// [/asset_report/create]: https://plaid.com/docs/api/products/assets/#asset_reportcreate
+    pub async fn asset_report_pdf(
+        &self,
+        asset_report_token: &str,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<u8>, Error> {
+        // TODO: make this strongly typed?
+        let body = json!({
+            "asset_report_token": asset_report_token,
+        });
+
+        self.send("/asset_report/pdf/get", body, timeout, None)
+            .await
+    }
+
+    /// Retrieve an Item
+    ///
+    /// [/item/get]
+    ///
+    /// Returns information about the status of an Item, including the last
+    /// successful and failed updates of its products and the last webhook
+    /// sent for it.
+    ///
+    /// [/item/get]: https://plaid.com/docs/api/items/#itemget
+    pub async fn item_get(&self, access_token: &str) -> Result<ItemGetResponse, Error> {
+        // TODO: make this strongly typed?
+        let body = json!({
+            "access_token": access_token,
+        });
+
+        self.post("/item/get", body).await
+    }
+
+    /// Remove an Item
+    ///
+    /// [/item/remove]
+    ///
+    /// Removes an Item. Once removed, its access token is no longer valid and
+    /// cannot be used to access any data that was associated with the Item.
+    ///
+    /// [/item/remove]: https://plaid.com/docs/api/items/#itemremove
+    pub async fn item_remove(&self, access_token: &str) -> Result<ItemRemoveResponse, Error> {
+        // TODO: make this strongly typed?
+        let body = json!({
+            "access_token": access_token,
+        });
+
+        self.post("/item/remove", body).await
+    }
+
+    /// Summarizes the health of an Item
+    ///
+    /// Retrieves the Item's accounts, which come with the Item itself, and
+    /// returns its errors, need for re-authentication and consent expiration
+    /// along with its number of accounts.
+    pub async fn item_health(&self, access_token: &str) -> Result<ItemHealth, Error> {
+        let response = self.accounts(access_token).await?;
+        Ok(ItemHealth::new(&response))
+    }
+
+    /// Update webhook callback URL
+    ///
+    /// [/item/webhook/update]
+    ///
+    /// Update the webhook URL associated with an Item. This request triggers a
+    /// WEBHOOK_UPDATE_ACKNOWLEDGED webhook to the newly specified webhook URL.
+    ///
+    /// [/item/webhook/update]: https://plaid.com/docs/api/items/#itemwebhookupdate
+    pub async fn update_webhook(
+        &self,
+        access_token: &str,
+        webhook_url: &str,
+    ) -> Result<WebhookUpdateResponse, Error> {
+        // TODO: make this strongly typed?
+        let body = json!({
+            "access_token": access_token,
+            "webhook": webhook_url
+        });
+
+        self.post("/item/webhook/update", body).await
+    }
+
+    /// Returns an `ItemClient` making requests for the Item with the given
+    /// access token, e.g. the one returned by `exchange_public_token`.
+    pub fn item<S>(&self, access_token: S) -> ItemClient<'_>
+    where
+        S: Into<String>,
+    {
+        ItemClient::new(self, access_token.into())
+    }
+
+    /// Waits for a product to be ready.
+    ///
+    /// Products such as Assets, Income or Investments return a
+    /// `PRODUCT_NOT_READY` error until the initial pull of their data is
+    /// complete. This makes `request` (the relevant get call) every
+    /// `poll_interval` until it stops returning that error, and returns its
+    /// result. Any other error is returned immediately. If the product is
+    /// still not ready after `timeout`, the last `PRODUCT_NOT_READY` error is
+    /// returned.
+    ///
+    /// ```no_run
+    /// # async fn example(client: plaid::Client, access_token: &str) -> Result<(), plaid::Error> {
+    /// use std::time::Duration;
+    ///
+    /// let auth = client
+    ///     .wait_for_product(Duration::from_secs(5), Duration::from_secs(60), |client| {
+    ///         client.auth(access_token, Default::default())
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_product<'a, F, Fut, T>(
+        &'a self,
+        poll_interval: Duration,
+        timeout: Duration,
+        mut request: F,
+    ) -> Result<T, Error>
+    where
+        F: FnMut(&'a Client) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let deadline = Instant::now() + timeout;
+        loop {
+            match request(self).await {
+                Err(Error::Api(error)) if error.error_code == "PRODUCT_NOT_READY" => {
+                    if Instant::now() + poll_interval > deadline {
+                        return Err(Error::Api(error));
+                    }
+                    tokio::time::sleep(poll_interval).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Sends a `POST` request with the given JSON body, authenticated with the
+    /// client's `Credentials`, to the given endpoint and parses the response.
+    async fn post<T>(&self, endpoint: &str, body: serde_json::Value) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let bytes = self
+            .send(endpoint, body, None, self.max_response_size)
+            .await?;
+        Ok(deserialize(&bytes)?)
+    }
+
+    /// Sends a `POST` request like `post`, returning the raw JSON of the
+    /// response along with the deserialized response.
+    async fn post_raw<T>(
+        &self,
+        endpoint: &str,
+        body: serde_json::Value,
+    ) -> Result<(T, serde_json::Value), Error>
+    where
+        T: DeserializeOwned,
+    {
+        let bytes = self
+            .send(endpoint, body, None, self.max_response_size)
+            .await?;
+        Ok((deserialize(&bytes)?, deserialize(&bytes)?))
+    }
+
+    /// Sends a `POST` request like `post`, optionally overriding the request
+    /// timeout, and returns the raw body of a successful response, of at most
+    /// `max_response_size` bytes.
+    async fn send(
+        &self,
+        endpoint: &str,
+        mut body: serde_json::Value,
+        timeout: Option<Duration>,
+        max_response_size: Option<usize>,
+    ) -> Result<Vec<u8>, Error> {
+        self.credentials.load().inject(&mut body);
+
+        let mut request = self
+            .client
+            .post(format!("{}{}", self.url, endpoint))
+            .json(&body);
+        if let Some(timeout) = timeout {
+            request = request.timeout(timeout);
+        }
+
+        let start = Instant::now();
+        let result = async {
+            let response = request.send().await?;
+
+            let status = response.status();
+            let bytes = read_body(response, max_response_size).await?;
+
+            match status {
+                StatusCode::OK | StatusCode::NO_CONTENT => Ok(bytes),
+                _ => Err(Error::Api(deserialize(&bytes)?)),
+            }
+        }
+        .await;
+
+        if let Some(metrics) = &self.metrics {
+            metrics.record(endpoint, result.as_ref().map(|_| ()), start.elapsed());
+        }
+        result
+    }
+}
+
+/// Reads the body of a response, failing with `Error::ResponseTooLarge` as
+/// soon as it is known to exceed `max_response_size` bytes.
+async fn read_body(
+    mut response: reqwest::Response,
+    max_response_size: Option<usize>,
+) -> Result<Vec<u8>, Error> {
+    let max_response_size = match max_response_size {
+        Some(max_response_size) => max_response_size,
+        None => return Ok(response.bytes().await?.to_vec()),
+    };
+
+    if let Some(len) = response.content_length() {
+        if len > max_response_size as u64 {
+            return Err(Error::ResponseTooLarge(max_response_size));
+        }
+    }
+
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if bytes.len() + chunk.len() > max_response_size {
+            return Err(Error::ResponseTooLarge(max_response_size));
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Ok(bytes)
+}
+
+fn env_var(name: &str) -> Result<String, FromEnvError> {
+    env::var(name).map_err(|error| match error {
+        env::VarError::NotPresent => FromEnvError::MissingVar(name.to_string()),
+        env::VarError::NotUnicode(_) => FromEnvError::InvalidVar(name.to_string()),
+    })
+}
+
+/// Deserializes a response body, reporting the path to the offending field on
+/// failure.
+///
+/// An empty body is deserialized as `null`, so that it can be read as `()`, a
+/// unit struct or an `Option`.
+fn deserialize<T>(bytes: &[u8]) -> Result<T, DeserializeError>
+where
+    T: DeserializeOwned,
+{
+    let bytes = if bytes.iter().all(u8::is_ascii_whitespace) {
+        b"null"
+    } else {
+        bytes
+    };
+    let de = &mut serde_json::Deserializer::from_slice(bytes);
+    serde_path_to_error::deserialize(de).map_err(|err| DeserializeError::new(err, bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as StdError;
+
+    use wiremock::matchers::{body_json, body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::fixtures::{self, mock_client};
+
+    async fn client_from_env() -> Result<(Client, String), Box<dyn StdError>> {
+        let client_id = dotenv::var("PLAID_CLIENT_ID")?;
+        let secret = dotenv::var("PLAID_SECRET")?;
         let client = Client::new(client_id, secret, Environment::Sandbox);
 
         let public_token = client
@@ -474,7 +1491,12 @@ mod tests {
     #[allow(clippy::unnecessary_operation)]
     async fn can_get_identity() {
         let (client, token) = client_from_env().await.unwrap();
-        let _ = &client.identity(&token).await.unwrap().accounts[0].owners[0];
+        let _ = &client
+            .identity(&token, Default::default())
+            .await
+            .unwrap()
+            .accounts[0]
+            .owners[0];
     }
 
     #[tokio::test]
@@ -503,6 +1525,8 @@ mod tests {
                     country_codes: vec![SupportedCountry::US],
                     user: EndUser {
                         client_user_id: "01234567-89AB-CDEF-0123-456789ABCDEF".to_string(),
+                        phone_number: None,
+                        email_address: None,
                     },
                     products: vec![SupportedProduct::Auth, SupportedProduct::Identity],
                     webhook: None,
@@ -513,12 +1537,653 @@ mod tests {
                     account_filters: None,
                     institution_id: None,
                     payment_initiation: None,
+                    hosted_link: None,
+                    user_token: None,
+                    transactions: None,
+                    billing_acknowledged: false,
                 },
             )
             .await
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn refuses_unacknowledged_billing_in_production() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/link/token/create"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "link_token": "link-production-840204-193734",
+                "expiration": "2020-03-27T12:56:34Z",
+                "request_id": "XQVgFigpGHXkb0b"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = Client::builder("client_id", "secret".to_string(), Environment::Production)
+            .base_url(server.uri())
+            .build();
+        let builder = CreateLinkTokenRequest::builder(
+            "My Client",
+            SupportedLanguage::en,
+            vec![SupportedCountry::US],
+            EndUser {
+                client_user_id: "01234567-89AB-CDEF-0123-456789ABCDEF".to_string(),
+                phone_number: None,
+                email_address: None,
+            },
+        )
+        .products(vec![
+            SupportedProduct::Auth,
+            SupportedProduct::Identity,
+            SupportedProduct::Transactions,
+        ]);
+
+        let request = builder.clone().build().unwrap();
+        let err = client.create_link_token(&request).await.unwrap_err();
+        match err {
+            Error::BillingNotAcknowledged(advisory) => {
+                assert_eq!(advisory.products, request.products)
+            }
+            err => panic!("unexpected error: {:?}", err),
+        }
+
+        let request = builder.acknowledge_billing().build().unwrap();
+        let response = client.create_link_token(&request).await.unwrap();
+        assert_eq!(response.link_token, "link-production-840204-193734");
+    }
+
+    #[test]
+    fn reports_deserialize_error_path() {
+        let body = br#"{ "public_token": 42, "request_id": "abc" }"#;
+        let err = deserialize::<SandboxCreatePublicTokenResponse>(body).unwrap_err();
+        assert_eq!(err.path(), "public_token");
+        assert_eq!(err.body().as_bytes(), &body[..]);
+    }
+
+    #[test]
+    fn can_use_other_credentials() {
+        let client = Client::new("client_id", "secret".to_string(), Environment::Sandbox);
+        let tenant =
+            client.with_credentials(Credentials::new("tenant_id", "tenant_secret".to_string()));
+
+        assert_eq!(tenant.credentials.load().client_id, "tenant_id");
+        assert_eq!(tenant.url, client.url);
+
+        tenant.rotate_secret("rotated_secret".to_string());
+        assert_eq!(client.credentials.load().client_id, "client_id");
+        assert_eq!(
+            serde_json::to_value(&client.credentials.load().secret).unwrap(),
+            "secret"
+        );
+    }
+
+    #[test]
+    fn builds_client_with_root_certificate() {
+        const CERTIFICATE: &str = "-----BEGIN CERTIFICATE-----
+MIIBhjCCASugAwIBAgIUF0gb1TnnoiHHN4WAkgNPCBXZWDwwCgYIKoZIzj0EAwIw
+GDEWMBQGA1UEAwwNUGxhaWQgVGVzdCBDQTAeFw0yNjEwMTYxNTU1MDlaFw0zNjEw
+MTMxNTU1MDlaMBgxFjAUBgNVBAMMDVBsYWlkIFRlc3QgQ0EwWTATBgcqhkjOPQIB
+BggqhkjOPQMBBwNCAATyf7Rj9KAW2wvt5cE+vEmE+eBhDrriWGqalfCKiClonIVt
+VkpZI0iHzKywjq+eBGXmHwr2S7iYcUHlV3JODTL7o1MwUTAdBgNVHQ4EFgQUfCwS
+rv17bW4LB6AB3QSqPJrXjGQwHwYDVR0jBBgwFoAUfCwSrv17bW4LB6AB3QSqPJrX
+jGQwDwYDVR0TAQH/BAUwAwEB/zAKBggqhkjOPQQDAgNJADBGAiEAsA+qOZsL0/6+
+KtFMKGK8ChtCObwia4LcH/fg9bJNllECIQCw9vlO3BLZY/XzZ14R1UO3UUmTbp+w
+Mqi6bBURyLdEqA==
+-----END CERTIFICATE-----
+";
+
+        let certificate = reqwest::Certificate::from_pem(CERTIFICATE.as_bytes()).unwrap();
+        let client = Client::builder("client_id", "secret".to_string(), Environment::Sandbox)
+            .add_root_certificate(certificate)
+            .tls_built_in_root_certs(false)
+            .try_build()
+            .unwrap();
+        assert_eq!(client.environment(), Environment::Sandbox);
+    }
+
+    #[tokio::test]
+    async fn waits_for_product() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/investments/refresh"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "error_type": "ITEM_ERROR",
+                "error_code": "PRODUCT_NOT_READY",
+                "error_message": "the requested product is not yet ready",
+                "display_message": null,
+                "request_id": "m8MDnv9okwxFNBV"
+            })))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/investments/refresh"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "request_id": "1vwmF5TBQwiqfwP" })),
+            )
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let result = client
+            .wait_for_product(Duration::from_millis(1), Duration::ZERO, |client| {
+                client.investments_refresh("access-sandbox")
+            })
+            .await;
+        assert!(
+            matches!(result, Err(Error::Api(error)) if error.error_code == "PRODUCT_NOT_READY")
+        );
+
+        let response = client
+            .wait_for_product(Duration::from_millis(1), Duration::from_secs(5), |client| {
+                client.investments_refresh("access-sandbox")
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.request_id, "1vwmF5TBQwiqfwP");
+    }
+
+    #[tokio::test]
+    async fn pages_through_all_transactions() {
+        fn transaction(id: &str, date: &str) -> serde_json::Value {
+            let mut transaction = fixtures::transaction();
+            transaction["transaction_id"] = json!(id);
+            transaction["date"] = json!(date);
+            transaction
+        }
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/transactions/sync"))
+            .and(body_partial_json(json!({ "cursor": "" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "added": [transaction("a", "2021-03-01"), transaction("b", "2021-03-02")],
+                "modified": [],
+                "removed": [],
+                "next_cursor": "page-2",
+                "has_more": true,
+                "request_id": "45QSn"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/transactions/sync"))
+            .and(body_partial_json(json!({ "cursor": "page-2" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "added": [transaction("c", "2021-03-03")],
+                "modified": [],
+                "removed": [{ "transaction_id": "a" }],
+                "next_cursor": "page-3",
+                "has_more": false,
+                "request_id": "8VGs1"
+            })))
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let transactions = client.transactions_all("access-sandbox").await.unwrap();
+        let ids: Vec<_> = transactions
+            .iter()
+            .map(|transaction| transaction.transaction_id.as_str())
+            .collect();
+        assert_eq!(ids, ["c", "b"]);
+    }
+
+    #[tokio::test]
+    async fn stops_restarting_invalidated_transactions_sync() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/transactions/sync"))
+            .and(body_partial_json(json!({ "cursor": "" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "added": [],
+                "modified": [],
+                "removed": [],
+                "next_cursor": "page-2",
+                "has_more": true,
+                "request_id": "45QSn"
+            })))
+            .expect(4)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/transactions/sync"))
+            .and(body_partial_json(json!({ "cursor": "page-2" })))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "error_type": "TRANSACTIONS_ERROR",
+                "error_code": "TRANSACTIONS_SYNC_MUTATION_DURING_PAGINATION",
+                "error_message": "Underlying transaction data changed since last page was fetched. Please restart pagination from last update.",
+                "display_message": null,
+                "request_id": "m8MDnv9okwxFNBV"
+            })))
+            .expect(4)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let result = client.transactions_all("access-sandbox").await;
+        assert!(matches!(
+            result,
+            Err(Error::Api(error))
+                if error.error_code == "TRANSACTIONS_SYNC_MUTATION_DURING_PAGINATION"
+        ));
+    }
+
+    #[tokio::test]
+    async fn fires_sandbox_webhook() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sandbox/item/fire_webhook"))
+            .and(body_json(json!({
+                "client_id": "client_id",
+                "secret": "secret",
+                "access_token": "access-sandbox",
+                "webhook_type": "ITEM",
+                "webhook_code": "LOGIN_REPAIRED"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "webhook_fired": true,
+                "request_id": "1vwmF5TBQwiqfwP"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let response = client
+            .sandbox_item_fire_webhook("access-sandbox", Some("ITEM"), "LOGIN_REPAIRED")
+            .await
+            .unwrap();
+        assert!(response.webhook_fired);
+    }
+
+    #[tokio::test]
+    async fn verifies_first_micro_deposit_account() {
+        let server = MockServer::start().await;
+        let mut accounts = fixtures::accounts_response();
+        let mut pending = fixtures::account();
+        pending["account_id"] = json!("pending");
+        pending["verification_status"] = json!("pending_manual_verification");
+        accounts["accounts"] = json!([fixtures::account(), pending]);
+        Mock::given(method("POST"))
+            .and(path("/accounts/get"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(accounts))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sandbox/item/set_verification_status"))
+            .and(body_partial_json(json!({
+                "access_token": "access-sandbox",
+                "account_id": "pending",
+                "verification_status": "manually_verified"
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "request_id": "zqErN4XEBg6ZyPH" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let response = client
+            .sandbox_verify_first_account("access-sandbox", VerificationStatus::ManuallyVerified)
+            .await
+            .unwrap();
+        assert_eq!(response.request_id, "zqErN4XEBg6ZyPH");
+    }
+
+    #[tokio::test]
+    async fn fails_to_verify_without_micro_deposit_account() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/accounts/get"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixtures::accounts_response()))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sandbox/item/set_verification_status"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let err = client
+            .sandbox_verify_first_account("access-sandbox", VerificationStatus::ManuallyVerified)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::NoVerifiableAccount));
+    }
+
+    #[tokio::test]
+    async fn records_metrics() {
+        use std::sync::Mutex;
+
+        #[derive(Default)]
+        struct Recorder(Mutex<Vec<(String, Option<String>)>>);
+
+        impl Metrics for Arc<Recorder> {
+            fn record(&self, endpoint: &str, result: Result<(), &Error>, _latency: Duration) {
+                let error_code = match result {
+                    Err(Error::Api(error)) => Some(error.error_code.clone()),
+                    _ => None,
+                };
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((endpoint.to_string(), error_code));
+            }
+        }
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/item/get"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "display_message": null,
+                "error_code": "INVALID_ACCESS_TOKEN",
+                "error_message": "provided access token is in an invalid format",
+                "error_type": "INVALID_INPUT",
+                "request_id": "m8MDnv9okwxFNBV"
+            })))
+            .mount(&server)
+            .await;
+        let recorder = Arc::new(Recorder::default());
+        let client = Client::builder("client_id", "secret".to_string(), Environment::Sandbox)
+            .base_url(server.uri())
+            .metrics(recorder.clone())
+            .build();
+        assert!(format!("{:?}", client).contains("metrics: true"));
+
+        assert!(client.item_get("access-sandbox").await.is_err());
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            [(
+                "/item/get".to_string(),
+                Some("INVALID_ACCESS_TOKEN".to_string())
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn limits_response_size() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/item/get"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(" ".repeat(1024)))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/asset_report/pdf/get"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0; 1024]))
+            .mount(&server)
+            .await;
+        let client = Client::builder("client_id", "secret".to_string(), Environment::Sandbox)
+            .base_url(server.uri())
+            .max_response_size(512)
+            .build();
+
+        let result = client.item_get("access-sandbox").await;
+        assert!(matches!(result, Err(Error::ResponseTooLarge(512))));
+
+        let pdf = client.asset_report_pdf("assets-sandbox", None).await;
+        assert_eq!(pdf.unwrap().len(), 1024);
+    }
+
+    #[tokio::test]
+    async fn pages_through_all_institutions() {
+        fn institution(id: &str) -> serde_json::Value {
+            let mut institution = fixtures::institution();
+            institution["institution_id"] = json!(id);
+            institution
+        }
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/institutions/get"))
+            .and(body_partial_json(json!({ "offset": 0 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "institutions": [institution("ins_1"), institution("ins_2")],
+                "total": 3,
+                "request_id": "45QSn"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/institutions/get"))
+            .and(body_partial_json(json!({ "offset": 2 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "institutions": [institution("ins_2"), institution("ins_3")],
+                "total": 3,
+                "request_id": "8VGs1"
+            })))
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let institutions = client
+            .institutions_all(&[SupportedCountry::US], &[])
+            .await
+            .unwrap();
+        let ids: Vec<_> = institutions
+            .iter()
+            .map(|institution| institution.institution_id.as_str())
+            .collect();
+        assert_eq!(ids, ["ins_1", "ins_2", "ins_3"]);
+    }
+
+    #[tokio::test]
+    async fn gets_accounts_of_several_items() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/accounts/get"))
+            .and(body_partial_json(
+                json!({ "access_token": "access-sandbox-1" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixtures::accounts_response()))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/accounts/get"))
+            .and(body_partial_json(
+                json!({ "access_token": "access-sandbox-2" }),
+            ))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "error_type": "INVALID_INPUT",
+                "error_code": "INVALID_ACCESS_TOKEN",
+                "error_message": "provided access token is in an invalid format",
+                "display_message": null,
+                "request_id": "m8MDnv9okwxFNBV"
+            })))
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let tokens = vec![
+            "access-sandbox-1".to_string(),
+            "access-sandbox-2".to_string(),
+        ];
+        let results = client.accounts_multi(&tokens, 0).await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "access-sandbox-1");
+        assert!(results[0].1.is_ok());
+        assert_eq!(results[1].0, "access-sandbox-2");
+        assert!(matches!(&results[1].1, Err(Error::Api(_))));
+    }
+
+    #[tokio::test]
+    async fn sends_account_ids_under_options() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/accounts/get"))
+            .and(body_json(json!({
+                "client_id": "client_id",
+                "secret": "secret",
+                "access_token": "access-sandbox",
+                "options": { "account_ids": ["blgvvBlXw3cq5GMPwqB6s6q4dLKB9WcVqGDGo"] }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixtures::accounts_response()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/accounts/get"))
+            .and(body_json(json!({
+                "client_id": "client_id",
+                "secret": "secret",
+                "access_token": "access-sandbox",
+                "options": { "account_ids": null }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixtures::accounts_response()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let options = AccountsGetRequestOptions {
+            account_ids: vec!["blgvvBlXw3cq5GMPwqB6s6q4dLKB9WcVqGDGo".to_string()],
+        };
+        client
+            .accounts_with_options("access-sandbox", options)
+            .await
+            .unwrap();
+        client.accounts("access-sandbox").await.unwrap();
+    }
+
+    #[test]
+    fn client_and_futures_are_send() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        fn assert_spawnable<T: Future + Send + 'static>(_: T) {}
+
+        assert_send_sync::<Client>();
+        assert_send_sync::<AccountsCache>();
+        assert_send_sync::<Error>();
+
+        let client = Client::new("client_id", "secret".to_string(), Environment::Sandbox);
+        let accounts = client.clone();
+        assert_spawnable(async move { accounts.accounts("access-sandbox").await });
+        let transactions = client.clone();
+        assert_spawnable(async move { transactions.transactions_all("access-sandbox").await });
+        let cache = AccountsCache::new(client.clone(), Duration::from_secs(60));
+        assert_spawnable(async move { cache.accounts("access-sandbox").await });
+        assert_spawnable(async move {
+            client
+                .wait_for_product(Duration::from_secs(1), Duration::from_secs(5), |client| {
+                    client.investments_refresh("access-sandbox")
+                })
+                .await
+        });
+    }
+
+    #[tokio::test]
+    async fn accepts_empty_bodies() {
+        #[derive(serde::Deserialize, Debug, PartialEq)]
+        struct EmptyResponse;
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/empty"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/no_content"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let response: EmptyResponse = client.post("/empty", json!({})).await.unwrap();
+        assert_eq!(response, EmptyResponse);
+        let response: Option<AccountsResponse> = client.post("/empty", json!({})).await.unwrap();
+        assert!(response.is_none());
+        client.post::<()>("/no_content", json!({})).await.unwrap();
+
+        let result = client.post::<AccountsResponse>("/empty", json!({})).await;
+        assert!(matches!(result, Err(Error::Deserialize(_))));
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_favicon() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/favicon.ico"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"icon".to_vec()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = Client::new("client_id", "secret".to_string(), Environment::Sandbox);
+
+        let mut institution: Institution = serde_json::from_value(json!({
+            "institution_id": "ins_109512",
+            "name": "Houndstooth Bank",
+            "products": ["auth"],
+            "country_codes": ["US"],
+            "url": format!("{}/about", server.uri()),
+            "logo": "aWNvbg==",
+            "oauth": false
+        }))
+        .unwrap();
+        let logo = client.institution_logo_or_favicon(&institution).await;
+        assert_eq!(logo.unwrap().unwrap(), b"icon");
+
+        institution.logo = None;
+        let favicon = client.institution_logo_or_favicon(&institution).await;
+        assert_eq!(favicon.unwrap().unwrap(), b"icon");
+
+        institution.url = None;
+        let none = client.institution_logo_or_favicon(&institution).await;
+        assert!(none.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn returns_raw_accounts_response() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/accounts/get"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixtures::accounts_response()))
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let (response, raw) = client.accounts_raw("access-sandbox").await.unwrap();
+        assert_eq!(response.request_id, "bkVE1BHWMAZ9Rnr");
+        assert_eq!(
+            raw["item"]["billed_products"],
+            json!(["auth", "transactions"])
+        );
+    }
+
+    #[test]
+    fn reports_invalid_env_vars() {
+        assert!(matches!(
+            env_var("PLAID_TEST_UNSET_VARIABLE"),
+            Err(FromEnvError::MissingVar(name)) if name == "PLAID_TEST_UNSET_VARIABLE"
+        ));
+        assert!(matches!(
+            Client::from_env_prefixed("PLAID_TEST_UNSET"),
+            Err(FromEnvError::MissingVar(name)) if name == "PLAID_TEST_UNSET_CLIENT_ID"
+        ));
+
+        let err = "staging".parse::<Environment>().unwrap_err();
+        assert_eq!(err.value(), "staging");
+        assert_eq!(err.to_string(), "invalid Plaid Environment: `staging`");
+    }
+
+    #[test]
+    fn builder_overrides_base_url() {
+        let client = Client::new("client_id", "secret".to_string(), Environment::Development);
+        assert_eq!(client.url, "https://development.plaid.com");
+
+        let client = Client::builder("client_id", "secret".to_string(), Environment::Sandbox)
+            .base_url("http://localhost:8080/")
+            .build();
+        assert_eq!(client.url, "http://localhost:8080");
+        assert_eq!(client.environment(), Environment::Sandbox);
+    }
+
     #[tokio::test]
     async fn can_handle_errors() {
         let client = Client::new(
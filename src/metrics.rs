@@ -0,0 +1,18 @@
+//! Opt-in metrics of the requests made by a `Client`.
+
+use std::time::Duration;
+
+use crate::Error;
+
+/// A sink for per-endpoint request metrics, e.g. to bridge to Prometheus or
+/// StatsD.
+///
+/// Set with `ClientBuilder::metrics`; `record` is called once for every
+/// request to the Plaid API, after its response has been read.
+pub trait Metrics: Send + Sync {
+    /// Records a request to `endpoint` (e.g. `"/accounts/get"`) that took
+    /// `latency` and either succeeded or failed with `result`'s error. The
+    /// `ErrorType` of errors reported by Plaid is available through
+    /// `Error::Api`.
+    fn record(&self, endpoint: &str, result: Result<(), &Error>, latency: Duration);
+}
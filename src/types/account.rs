@@ -1,15 +1,24 @@
 //! Account types.
 
+use std::num::ParseFloatError;
+
 use serde::{Deserialize, Serialize};
 
+use super::Product;
+
 /// The response from performing an `accounts` request.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct AccountsResponse {
     /// The financial institution accounts associated with the Item.
     #[serde(default)]
     pub accounts: Vec<Account>,
 
     /// Metadata about the Item.
+    ///
+    /// *Note*: `item.error` may be populated even though the request
+    /// succeeded, e.g. when the Item requires the user to log in again. The
+    /// accounts returned then reflect the last successful update.
     pub item: super::Item,
 
     /// A unique identifier for the request, which can be used for
@@ -18,8 +27,120 @@ pub struct AccountsResponse {
     pub request_id: String,
 }
 
+impl AccountsResponse {
+    /// Returns `true` if an account with the given `account_id` is associated
+    /// with the Item.
+    pub fn contains_account(&self, account_id: &str) -> bool {
+        self.accounts.iter().any(|a| a.account_id == account_id)
+    }
+
+    /// Returns the given `account_ids` that are not associated with the Item.
+    ///
+    /// Requests accepting `account_ids` in their options return an
+    /// `INVALID_INPUT` error if any of them are not associated with the Item;
+    /// this can be used to check them beforehand against a previously
+    /// retrieved `AccountsResponse`.
+    pub fn unknown_account_ids<'a>(&self, account_ids: &'a [String]) -> Vec<&'a str> {
+        account_ids
+            .iter()
+            .map(String::as_str)
+            .filter(|id| !self.contains_account(id))
+            .collect()
+    }
+
+    /// The net worth of the accounts in the given currency: the sum of the
+    /// `current` balances of depository and investment accounts, minus those
+    /// of credit and loan accounts, which are debts.
+    ///
+    /// Accounts of other types, in other currencies or without a `current`
+    /// balance are skipped; use `Account::balance_in` to convert balances
+    /// beforehand. Returns `None` if no account is counted.
+    pub fn net_worth(&self, currency: &str) -> Option<f64> {
+        self.accounts
+            .iter()
+            .filter(|account| {
+                account
+                    .balances
+                    .currency_code()
+                    .is_some_and(|code| code.eq_ignore_ascii_case(currency))
+            })
+            .filter_map(|account| {
+                let current = account.balances.current?;
+                match account.ty {
+                    AccountType::Depository | AccountType::Investment => Some(current),
+                    AccountType::Credit | AccountType::Loan => Some(-current),
+                    AccountType::Other => None,
+                }
+            })
+            .fold(None, |total, balance| Some(total.unwrap_or(0.0) + balance))
+    }
+
+    /// Compares the accounts of this response with a `previous` snapshot of
+    /// the accounts of the same Item.
+    ///
+    /// Plaid assigns a new `account_id` to an account it cannot reconcile with
+    /// the data of the institution, so an account whose `account_id` is new is
+    /// matched with a vanished account of the same `mask`, `name` and type, and
+    /// reported as remapped; the others are reported as added or removed.
+    pub fn reconcile<'a>(&'a self, previous: &'a [Account]) -> Reconciliation<'a> {
+        let mut removed: Vec<&Account> = previous
+            .iter()
+            .filter(|account| !self.contains_account(&account.account_id))
+            .collect();
+
+        let mut reconciliation = Reconciliation::default();
+        for account in &self.accounts {
+            if previous
+                .iter()
+                .any(|previous| previous.account_id == account.account_id)
+            {
+                continue;
+            }
+
+            match removed
+                .iter()
+                .position(|previous| previous.is_same_account(account))
+            {
+                Some(index) => reconciliation.remapped.push(RemappedAccount {
+                    previous: removed.remove(index),
+                    current: account,
+                }),
+                None => reconciliation.added.push(account),
+            }
+        }
+        reconciliation.removed = removed;
+        reconciliation
+    }
+}
+
+/// The differences between two snapshots of the accounts of an Item, as
+/// returned by `AccountsResponse::reconcile`.
+#[derive(Clone, Debug, Default)]
+pub struct Reconciliation<'a> {
+    /// The accounts that are new in the current snapshot.
+    pub added: Vec<&'a Account>,
+
+    /// The accounts of the previous snapshot that are no longer present.
+    pub removed: Vec<&'a Account>,
+
+    /// The accounts that are present in both snapshots under different
+    /// `account_id`s.
+    pub remapped: Vec<RemappedAccount<'a>>,
+}
+
+/// An account whose `account_id` changed between two snapshots.
+#[derive(Clone, Copy, Debug)]
+pub struct RemappedAccount<'a> {
+    /// The account in the previous snapshot, with its former `account_id`.
+    pub previous: &'a Account,
+
+    /// The account in the current snapshot, with its new `account_id`.
+    pub current: &'a Account,
+}
+
 /// Financial institution accounts associated with the `Item`.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Account {
     /// Plaid’s unique identifier for the account. This value will not change
     /// unless Plaid can't reconcile the account with the data returned by the
@@ -85,6 +206,29 @@ pub struct Account {
     pub days_available: Option<u32>,
 }
 
+impl Account {
+    /// Returns `true` if `other` has the same `mask`, `name` and type, and is
+    /// thus likely the same account under another `account_id`.
+    fn is_same_account(&self, other: &Account) -> bool {
+        self.mask == other.mask && self.name == other.name && self.ty == other.ty
+    }
+
+    /// The `current` balance of the account converted into the `target`
+    /// currency, using exchange rates from `rates`.
+    ///
+    /// Balances in an unofficial currency are converted from their
+    /// `unofficial_currency_code`. Returns `None` if the account has no
+    /// current balance or currency, or if `rates` has no rate for the pair.
+    pub fn balance_in(&self, target: &str, rates: &dyn FxRateProvider) -> Option<f64> {
+        let current = self.balances.current?;
+        let currency = self.balances.currency_code()?;
+        if currency.eq_ignore_ascii_case(target) {
+            return Some(current);
+        }
+        Some(current * rates.rate(currency, target)?)
+    }
+}
+
 // TODO: use a money crate
 // TODO: use tagged enum instead of both currency fields
 /// A set of fields describing the balance for an account.
@@ -93,6 +237,7 @@ pub struct Account {
 /// guaranteed to be up-to-date in realtime unless the balance object was
 /// returned by `/account/balance/get`.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Balances {
     /// The amount of funds available to be withdrawn from the account, as
     /// determined by the financial institution.
@@ -117,6 +262,10 @@ pub struct Balances {
     /// in realtime unless the value was returned by `/account/balance/get`.
     ///
     /// If current is null this field is guaranteed not to be null.
+    #[serde(
+        default,
+        deserialize_with = "super::serde_utils::num_or_str::option::deserialize"
+    )]
     pub available: Option<f64>,
 
     /// The total amount of funds in or owed by the account.
@@ -135,6 +284,10 @@ pub struct Balances {
     ///
     /// When returned by /accounts/balance/get, this field may be null. When
     /// this happens, available is guaranteed not to be null.
+    #[serde(
+        default,
+        deserialize_with = "super::serde_utils::num_or_str::option::deserialize"
+    )]
     pub current: Option<f64>,
 
     /// For credit-type accounts, this represents the credit limit.
@@ -144,6 +297,10 @@ pub struct Balances {
     ///
     /// In North America, this field is typically only available for credit-type
     /// accounts.
+    #[serde(
+        default,
+        deserialize_with = "super::serde_utils::num_or_str::option::deserialize"
+    )]
     pub limit: Option<f64>,
 
     // TODO: use ISO 4217 library
@@ -160,11 +317,43 @@ pub struct Balances {
     pub unofficial_currency_code: Option<String>,
 }
 
+impl Balances {
+    /// The `available` balance, falling back to the `current` balance for
+    /// institutions that do not report it.
+    ///
+    /// Only `None` if neither is reported; Plaid guarantees that `available`
+    /// is set whenever `current` is null.
+    pub fn available_or_current(&self) -> Option<f64> {
+        self.available.or(self.current)
+    }
+
+    /// The ISO 4217 currency code of the balance, falling back to its
+    /// unofficial currency code.
+    pub fn currency_code(&self) -> Option<&str> {
+        self.iso_currency_code
+            .as_deref()
+            .or(self.unofficial_currency_code.as_deref())
+    }
+}
+
+/// A source of exchange rates for converting account balances, as used by
+/// `Account::balance_in`.
+///
+/// The library does not fetch exchange rates itself.
+pub trait FxRateProvider {
+    /// The amount of the `to` currency that one unit of the `from` currency
+    /// buys, or `None` if the rate is unknown. Currencies are given as they
+    /// appear in `Balances`, usually as ISO 4217 codes.
+    fn rate(&self, from: &str, to: &str) -> Option<f64>;
+}
+
 // TODO: use tagged enum instead of both currency fields
 /// An account balance from a specific point in time.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct HistoricalBalance {
     /// The date of the calculated historical balance.
+    #[serde(with = "super::serde_utils::plaid_date")]
     pub date: chrono::NaiveDate,
 
     /// The total amount of funds in the account, calculated from the current
@@ -175,6 +364,11 @@ pub struct HistoricalBalance {
     /// on or after the date of the earliest pending transaction may differ if
     /// retrieved in subsequent Asset Reports as a result of those pending
     /// transactions posting.
+    ///
+    /// Unlike `Balances::current`, this is kept in the decimal representation
+    /// used by the Assets endpoints rather than as a float, so that no
+    /// precision is lost; use `current_amount` or `current_minor_units` to
+    /// parse it.
     pub current: String,
 
     // TODO: use ISO 4217 library
@@ -191,6 +385,60 @@ pub struct HistoricalBalance {
     pub unofficial_currency_code: Option<String>,
 }
 
+impl HistoricalBalance {
+    /// Parses `current` as a float.
+    pub fn current_amount(&self) -> Result<f64, ParseFloatError> {
+        self.current.trim().parse()
+    }
+
+    /// Parses `current` as an integer amount of minor units (e.g. cents),
+    /// given the number of decimal places of the currency (e.g. `2` for USD).
+    ///
+    /// Returns `None` if `current` is not a decimal number, if it has more
+    /// significant decimal places than given, or if the amount overflows.
+    pub fn current_minor_units(&self, decimal_places: u32) -> Option<i64> {
+        parse_minor_units(&self.current, decimal_places)
+    }
+}
+
+/// Parses a decimal string (e.g. `"-1234.5"`) into an integer amount of minor
+/// units, without going through a float.
+fn parse_minor_units(value: &str, decimal_places: u32) -> Option<i64> {
+    let value = value.trim();
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(value) => (true, value),
+        None => (false, value),
+    };
+    let (integer, fraction) = match value.find('.') {
+        Some(i) => (&value[..i], &value[i + 1..]),
+        None => (value, ""),
+    };
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    if !integer
+        .chars()
+        .chain(fraction.chars())
+        .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let places = decimal_places as usize;
+    if fraction.len() > places && fraction[places..].chars().any(|c| c != '0') {
+        return None;
+    }
+
+    let mut units: i64 = 0;
+    let fraction_digits = fraction.chars().chain(std::iter::repeat('0')).take(places);
+    for c in integer.chars().chain(fraction_digits) {
+        let digit = i64::from(c.to_digit(10)?);
+        units = units.checked_mul(10)?.checked_add(digit)?;
+    }
+
+    Some(if negative { -units } else { units })
+}
+
 /// Account holder(s) information.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Owner {
@@ -231,6 +479,34 @@ pub struct Owner {
     pub addresses: Vec<Address>,
 }
 
+impl Owner {
+    /// The phone number flagged as primary, or the first phone number if none
+    /// is.
+    pub fn primary_phone(&self) -> Option<&PhoneNumber> {
+        self.phone_numbers
+            .iter()
+            .find(|p| p.primary == Some(true))
+            .or_else(|| self.phone_numbers.first())
+    }
+
+    /// The email address flagged as primary, or the first email address if
+    /// none is.
+    pub fn primary_email(&self) -> Option<&EmailAddress> {
+        self.emails
+            .iter()
+            .find(|e| e.primary)
+            .or_else(|| self.emails.first())
+    }
+
+    /// The address flagged as primary, or the first address if none is.
+    pub fn primary_address(&self) -> Option<&Address> {
+        self.addresses
+            .iter()
+            .find(|a| a.primary == Some(true))
+            .or_else(|| self.addresses.first())
+    }
+}
+
 /// Details Phone number associated with an `Account`.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PhoneNumber {
@@ -326,9 +602,51 @@ pub struct AddressDetails {
     pub country: Option<String>,
 }
 
+impl AddressDetails {
+    /// Formats the address on a single line, joining its components with
+    /// commas and skipping missing ones, e.g.
+    /// `"2992 Cameron Road, Malakoff, NY 14236, US"`.
+    pub fn format_single_line(&self) -> String {
+        self.lines().join(", ")
+    }
+
+    /// Formats the address on up to three lines: the street, then the city,
+    /// region and postal code, then the country, skipping missing components,
+    /// e.g. `"2992 Cameron Road\nMalakoff, NY 14236\nUS"`.
+    pub fn format_multi_line(&self) -> String {
+        self.lines().join("\n")
+    }
+
+    fn lines(&self) -> Vec<String> {
+        let region_postal_code = join(&[&self.region, &self.postal_code], " ");
+        let locality = join(&[&self.city, &region_postal_code], ", ");
+
+        [Some(self.street.clone()), locality, self.country.clone()]
+            .iter()
+            .filter_map(|line| present(line))
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// Returns the trimmed value, if it is not empty.
+fn present(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
+
+/// Joins the present values with the separator, if any are present.
+fn join(values: &[&Option<String>], separator: &str) -> Option<String> {
+    let values: Vec<_> = values.iter().filter_map(|v| present(v)).collect();
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.join(separator))
+    }
+}
+
 // TODO: add account sub-types; how do we handle ser/de?
 /// Account types.
-#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum AccountType {
     /// An account type holding cash, in which funds are deposited.
@@ -361,6 +679,41 @@ pub enum AccountType {
     Other,
 }
 
+impl AccountType {
+    /// The name of the account type, as serialized, e.g. `"depository"`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            AccountType::Depository => "depository",
+            AccountType::Credit => "credit",
+            AccountType::Loan => "loan",
+            AccountType::Investment => "investment",
+            AccountType::Other => "other",
+        }
+    }
+
+    /// Returns `true` if `product` can be used with accounts of this type,
+    /// according to the products listed for each type.
+    ///
+    /// Products not listed for any account type, such as `Transfer`, are
+    /// reported as unsupported.
+    pub fn supports(&self, product: Product) -> bool {
+        use Product::*;
+
+        match self {
+            AccountType::Depository => matches!(
+                product,
+                Auth | Balance | Transactions | Identity | PaymentInitiation | Assets
+            ),
+            AccountType::Credit => {
+                matches!(product, Balance | Transactions | Identity | Liabilities)
+            }
+            AccountType::Loan => matches!(product, Balance | Liabilities | Transactions),
+            AccountType::Investment => matches!(product, Balance | Investments),
+            AccountType::Other => matches!(product, Balance | Transactions | Identity | Assets),
+        }
+    }
+}
+
 /// The current verification status of an Auth Item initiated through Automated
 /// or Manual micro-deposits. Returned for Auth Items only.
 #[derive(Serialize, Deserialize, Clone, Copy, Debug)]
@@ -385,6 +738,44 @@ pub enum VerificationStatus {
     VerificationExpired,
 }
 
+impl VerificationStatus {
+    /// Returns `true` if the Item is still awaiting automatic or manual
+    /// micro-deposit verification.
+    pub fn is_pending(&self) -> bool {
+        matches!(
+            self,
+            VerificationStatus::PendingAutomaticVerification
+                | VerificationStatus::PendingManualVerification
+        )
+    }
+
+    /// Returns `true` if the Item has been verified, either automatically or
+    /// manually.
+    pub fn is_verified(&self) -> bool {
+        matches!(
+            self,
+            VerificationStatus::AutomaticallyVerified | VerificationStatus::ManuallyVerified
+        )
+    }
+
+    /// Returns `true` if verification expired and the user must retry
+    /// through Link.
+    pub fn is_failed(&self) -> bool {
+        matches!(self, VerificationStatus::VerificationExpired)
+    }
+}
+
+/// The response from performing a `sandbox_item_set_verification_status`
+/// request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SandboxItemSetVerificationStatusResponse {
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
+
 /// Options for the `balance` request.
 #[derive(Serialize, Deserialize, Default, Clone, Debug)]
 pub struct BalanceRequestOptions {
@@ -407,3 +798,257 @@ pub struct BalanceRequestOptions {
     /// returned. For all other institutions, this field is ignored.
     pub min_last_updated_datetime: Option<String>,
 }
+
+/// Options for the `accounts_with_options` request.
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct AccountsGetRequestOptions {
+    /// An array of `account_ids` to retrieve for the Item.
+    ///
+    /// *Note*: An error will be returned if a provided `account_id` is not
+    /// associated with the Item.
+    #[serde(default, with = "super::serde_utils::default_on_null")]
+    pub account_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn reconciles_account_snapshots() {
+        fn account(id: &str, mask: &str, name: &str) -> Account {
+            let mut account = fixtures::account();
+            account["account_id"] = json!(id);
+            account["mask"] = json!(mask);
+            account["name"] = json!(name);
+            serde_json::from_value(account).unwrap()
+        }
+
+        let previous = vec![
+            account("checking", "0000", "Plaid Checking"),
+            account("savings", "1111", "Plaid Saving"),
+            account("cd", "2222", "Plaid CD"),
+        ];
+        let mut value = fixtures::accounts_response();
+        value["accounts"] = json!([
+            account("checking", "0000", "Plaid Checking"),
+            account("savings-2", "1111", "Plaid Saving"),
+            account("credit", "3333", "Plaid Credit Card"),
+        ]);
+        let response: AccountsResponse = serde_json::from_value(value).unwrap();
+
+        let reconciliation = response.reconcile(&previous);
+        let ids = |accounts: &[&Account]| -> Vec<String> {
+            accounts.iter().map(|a| a.account_id.clone()).collect()
+        };
+        assert_eq!(ids(&reconciliation.added), ["credit"]);
+        assert_eq!(ids(&reconciliation.removed), ["cd"]);
+        assert_eq!(reconciliation.remapped.len(), 1);
+        assert_eq!(reconciliation.remapped[0].previous.account_id, "savings");
+        assert_eq!(reconciliation.remapped[0].current.account_id, "savings-2");
+    }
+
+    #[test]
+    fn can_deserialize_string_balances() {
+        let balances: Balances = serde_json::from_value(json!({
+            "available": "100.25",
+            "current": 110,
+            "limit": null,
+            "iso_currency_code": "USD",
+            "unofficial_currency_code": null
+        }))
+        .unwrap();
+        assert_eq!(balances.available, Some(100.25));
+        assert_eq!(balances.current, Some(110.0));
+        assert_eq!(balances.limit, None);
+    }
+
+    #[test]
+    fn computes_net_worth() {
+        fn account(ty: &str, current: f64, currency: &str) -> serde_json::Value {
+            let mut account = fixtures::account();
+            account["type"] = json!(ty);
+            account["balances"]["current"] = json!(current);
+            account["balances"]["iso_currency_code"] = json!(currency);
+            account
+        }
+
+        let mut value = fixtures::accounts_response();
+        value["accounts"] = json!([
+            account("depository", 1000.0, "USD"),
+            account("investment", 500.0, "USD"),
+            account("credit", 200.0, "USD"),
+            account("loan", 300.0, "USD"),
+            account("other", 50.0, "USD"),
+            account("depository", 400.0, "EUR"),
+        ]);
+        let response: AccountsResponse = serde_json::from_value(value).unwrap();
+
+        assert_eq!(response.net_worth("USD"), Some(1000.0));
+        assert_eq!(response.net_worth("EUR"), Some(400.0));
+        assert_eq!(response.net_worth("GBP"), None);
+    }
+
+    #[test]
+    fn converts_balance_into_another_currency() {
+        struct Rates;
+
+        impl FxRateProvider for Rates {
+            fn rate(&self, from: &str, to: &str) -> Option<f64> {
+                match (from, to) {
+                    ("EUR", "USD") => Some(1.25),
+                    _ => None,
+                }
+            }
+        }
+
+        let mut value = fixtures::account();
+        value["balances"]["iso_currency_code"] = json!("EUR");
+        let mut account: Account = serde_json::from_value(value).unwrap();
+
+        assert_eq!(account.balance_in("USD", &Rates), Some(137.5));
+        assert_eq!(account.balance_in("EUR", &Rates), Some(110.0));
+        assert_eq!(account.balance_in("GBP", &Rates), None);
+
+        account.balances.iso_currency_code = None;
+        assert_eq!(account.balance_in("USD", &Rates), None);
+    }
+
+    #[test]
+    fn can_get_primary_contact_details() {
+        let owner: Owner = serde_json::from_value(json!({
+            "names": ["Alberta Bobbeth Charleson"],
+            "phone_numbers": [
+                { "data": "1112223333", "primary": false, "type": "home" },
+                { "data": "1112224444", "primary": true, "type": "work" }
+            ],
+            "emails": [
+                { "data": "accountholder0@example.com", "primary": false, "type": "secondary" },
+                { "data": "extraordinarily.long.email.username.123456@reallylonghostname.com", "primary": false, "type": "other" }
+            ],
+            "addresses": []
+        }))
+        .unwrap();
+
+        assert_eq!(owner.primary_phone().unwrap().data, "1112224444");
+        assert_eq!(
+            owner.primary_email().unwrap().data,
+            "accountholder0@example.com"
+        );
+        assert!(owner.primary_address().is_none());
+    }
+
+    #[test]
+    fn can_deserialize_accounts_with_item_error() {
+        let mut value = fixtures::accounts_response();
+        value["item"]["error"] = json!({
+            "error_type": "ITEM_ERROR",
+            "error_code": "ITEM_LOGIN_REQUIRED",
+            "error_message": "the login details of this item have changed",
+            "display_message": null,
+            "request_id": null
+        });
+        let response: AccountsResponse = serde_json::from_value(value).unwrap();
+
+        assert_eq!(response.accounts.len(), 1);
+        assert!(response.item.needs_reauth());
+    }
+
+    #[test]
+    fn available_balance_falls_back_to_current() {
+        let balances: Balances = serde_json::from_value(json!({
+            "available": null,
+            "current": 110.5,
+            "limit": null,
+            "iso_currency_code": "USD",
+            "unofficial_currency_code": null
+        }))
+        .unwrap();
+        assert_eq!(balances.available_or_current(), Some(110.5));
+
+        let balances = Balances {
+            available: Some(100.0),
+            ..balances
+        };
+        assert_eq!(balances.available_or_current(), Some(100.0));
+    }
+
+    #[test]
+    fn can_format_full_address() {
+        let address = AddressDetails {
+            city: Some("Malakoff".to_string()),
+            region: Some("NY".to_string()),
+            street: "2992 Cameron Road".to_string(),
+            postal_code: Some("14236".to_string()),
+            country: Some("US".to_string()),
+        };
+        assert_eq!(
+            address.format_single_line(),
+            "2992 Cameron Road, Malakoff, NY 14236, US"
+        );
+        assert_eq!(
+            address.format_multi_line(),
+            "2992 Cameron Road\nMalakoff, NY 14236\nUS"
+        );
+    }
+
+    #[test]
+    fn can_format_sparse_address() {
+        let address = AddressDetails {
+            city: None,
+            region: Some("NY".to_string()),
+            street: "2992 Cameron Road".to_string(),
+            postal_code: None,
+            country: None,
+        };
+        assert_eq!(address.format_single_line(), "2992 Cameron Road, NY");
+        assert_eq!(address.format_multi_line(), "2992 Cameron Road\nNY");
+
+        let address = AddressDetails {
+            city: Some("Malakoff".to_string()),
+            region: None,
+            street: "".to_string(),
+            postal_code: Some(" ".to_string()),
+            country: Some("US".to_string()),
+        };
+        assert_eq!(address.format_single_line(), "Malakoff, US");
+    }
+
+    #[test]
+    fn checks_account_type_products() {
+        assert!(AccountType::Depository.supports(Product::Auth));
+        assert!(!AccountType::Credit.supports(Product::Auth));
+        assert!(AccountType::Credit.supports(Product::Liabilities));
+        assert!(AccountType::Investment.supports(Product::Balance));
+        assert!(!AccountType::Investment.supports(Product::Transactions));
+        assert!(!AccountType::Other.supports(Product::Unknown));
+    }
+
+    #[test]
+    fn groups_verification_statuses() {
+        let status: VerificationStatus =
+            serde_json::from_value(json!("pending_manual_verification")).unwrap();
+        assert!(status.is_pending());
+        assert!(!status.is_verified());
+
+        assert!(VerificationStatus::ManuallyVerified.is_verified());
+        assert!(VerificationStatus::VerificationExpired.is_failed());
+        assert!(!VerificationStatus::AutomaticallyVerified.is_failed());
+    }
+
+    #[test]
+    fn can_parse_minor_units() {
+        assert_eq!(parse_minor_units("1234.56", 2), Some(123456));
+        assert_eq!(parse_minor_units("-0.5", 2), Some(-50));
+        assert_eq!(parse_minor_units("12", 2), Some(1200));
+        assert_eq!(parse_minor_units("12.340", 2), Some(1234));
+        assert_eq!(parse_minor_units("1500", 0), Some(1500));
+        assert_eq!(parse_minor_units("12.345", 2), None);
+        assert_eq!(parse_minor_units("1e3", 2), None);
+        assert_eq!(parse_minor_units("", 2), None);
+        assert_eq!(parse_minor_units("99999999999999999999", 2), None);
+    }
+}
@@ -0,0 +1,155 @@
+//! Amount types.
+
+use std::convert::TryFrom;
+use std::error::Error as StdError;
+use std::fmt;
+use std::str::FromStr;
+
+pub use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An amount of money moved by a transfer, e.g. `"10.00"`.
+///
+/// Unlike the `f64` balances, which are for display only, an `Amount` is
+/// backed by a `Decimal` so that no precision is lost. It is serialized as a
+/// decimal string with exactly two digits of precision, as Plaid expects.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Amount(Decimal);
+
+impl Amount {
+    /// The number of digits after the decimal point of an `Amount`.
+    pub const DECIMAL_PLACES: u32 = 2;
+
+    /// Creates an `Amount`, checking that it is not negative and has at most
+    /// `DECIMAL_PLACES` digits after the decimal point.
+    pub fn new(value: Decimal) -> Result<Self, AmountError> {
+        if value.is_sign_negative() && !value.is_zero() {
+            return Err(AmountError::Negative);
+        }
+        if value.normalize().scale() > Self::DECIMAL_PLACES {
+            return Err(AmountError::TooPrecise);
+        }
+
+        let mut value = value.normalize();
+        value.rescale(Self::DECIMAL_PLACES);
+        Ok(Amount(value))
+    }
+
+    /// The value of the amount.
+    pub fn value(&self) -> Decimal {
+        self.0
+    }
+}
+
+impl TryFrom<Decimal> for Amount {
+    type Error = AmountError;
+
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        Amount::new(value)
+    }
+}
+
+impl From<Amount> for Decimal {
+    fn from(amount: Amount) -> Self {
+        amount.0
+    }
+}
+
+impl FromStr for Amount {
+    type Err = AmountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = Decimal::from_str_exact(s).map_err(|_| AmountError::Invalid(s.to_string()))?;
+        Amount::new(value)
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serialize for Amount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// An error returned when creating an invalid `Amount`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AmountError {
+    /// The value is not a decimal number.
+    Invalid(String),
+
+    /// The value is negative.
+    Negative,
+
+    /// The value has more than `Amount::DECIMAL_PLACES` digits after the
+    /// decimal point.
+    TooPrecise,
+}
+
+impl StdError for AmountError {}
+
+impl fmt::Display for AmountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AmountError::Invalid(value) => write!(f, "invalid amount: `{}`", value),
+            AmountError::Negative => write!(f, "amount must not be negative"),
+            AmountError::TooPrecise => write!(
+                f,
+                "amount must have at most {} decimal places",
+                Amount::DECIMAL_PLACES
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn can_serde_amount() {
+        let amount: Amount = serde_json::from_value(json!("10.5")).unwrap();
+        assert_eq!(amount.value(), Decimal::new(1050, 2));
+        assert_eq!(serde_json::to_value(amount).unwrap(), json!("10.50"));
+
+        let amount = Amount::new(Decimal::new(3, 0)).unwrap();
+        assert_eq!(amount.to_string(), "3.00");
+    }
+
+    #[test]
+    fn keeps_precision() {
+        let sum =
+            "0.10".parse::<Amount>().unwrap().value() + "0.20".parse::<Amount>().unwrap().value();
+        assert_eq!(Amount::new(sum).unwrap().to_string(), "0.30");
+    }
+
+    #[test]
+    fn rejects_invalid_amounts() {
+        assert_eq!("-1.00".parse::<Amount>(), Err(AmountError::Negative));
+        assert_eq!("1.005".parse::<Amount>(), Err(AmountError::TooPrecise));
+        assert_eq!(
+            "ten".parse::<Amount>(),
+            Err(AmountError::Invalid("ten".to_string()))
+        );
+        assert!(serde_json::from_value::<Amount>(json!(10.5)).is_err());
+    }
+}
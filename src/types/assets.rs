@@ -0,0 +1,600 @@
+//! Assets types.
+
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+/// Description of the kind of webhook
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(tag = "webhook_code")]
+pub enum AssetsWebhookCode {
+    /// Fired when the Asset Report has been generated and `/asset_report/get` is ready to be called. If you attempt to retrieve an Asset Report before this webhook has fired, you’ll receive a response with the HTTP status code 400 and a Plaid error code of `PRODUCT_NOT_READY`.
+    ProductReady {
+        /// Indicates whether the report is a full or fast Asset Report.
+        report_type: Option<AssetReportType>,
+    },
+    /// Fired when Asset Report generation has failed. The resulting error is available in the `error` field of the `Webhook`.
+    Error,
+}
+
+/// Webhooks are used to communicate the completion (or failure) of Asset Report generation. All `Assets` webhooks have a `webhook_type` of `ASSETS`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AssetsWebhook {
+    /// Description of the kind of webhook
+    #[serde(flatten)]
+    pub webhook_code: AssetsWebhookCode,
+    /// The `asset_report_id` of the Asset Report associated with this webhook
+    pub asset_report_id: String,
+    /// The `user_id` corresponding to the User ID the webhook has fired for, if any
+    pub user_id: Option<String>,
+}
+
+/// Whether an Asset Report is a full or fast report.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AssetReportType {
+    /// A full Asset Report.
+    Full,
+    /// A fast Asset Report, which omits transactions and identity data.
+    Fast,
+}
+
+/// The body for the `asset_report_create` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AssetReportCreateRequest {
+    /// An array of access tokens corresponding to the Items that will be
+    /// included in the report. The `assets` product must have been initialized
+    /// for the Items during link; the Assets product cannot be added after
+    /// initialization.
+    pub access_tokens: Vec<String>,
+
+    /// The maximum integer number of days of history to include in the Asset
+    /// Report. If using Fannie Mae Day 1 Certainty, `days_requested` must be at
+    /// least 61 for new originations or at least 31 for refinancings.
+    ///
+    /// Maximum: `731`.
+    pub days_requested: u32,
+
+    /// The options for configuring the Asset Report.
+    #[serde(default)]
+    pub options: AssetReportCreateRequestOptions,
+}
+
+/// The options for configuring an Asset Report.
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct AssetReportCreateRequestOptions {
+    /// Client-generated identifier, which can be used by lenders to track
+    /// loan applications.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_report_id: Option<String>,
+
+    /// URL to which Plaid will send Assets webhooks, for example when the
+    /// requested Asset Report is ready.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<String>,
+
+    /// The user object allows you to provide additional information about
+    /// the user to be appended to the Asset Report.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<AssetReportUser>,
+}
+
+/// Information about the user to be appended to an Asset Report.
+///
+/// All fields are optional. The fields in this object are not validated by
+/// Plaid and are simply appended to the report.
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct AssetReportUser {
+    /// An identifier you determine and submit for the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_user_id: Option<String>,
+
+    /// The user's first name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+
+    /// The user's middle name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub middle_name: Option<String>,
+
+    /// The user's last name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+
+    /// The user's Social Security Number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssn: Option<String>,
+
+    /// The user's phone number, in E.164 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+
+    /// The user's email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
+/// The response from performing an `asset_report_create` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AssetReportCreateResponse {
+    /// A token that can be provided to endpoints such as `/asset_report/get`
+    /// or `/asset_report/pdf/get` to fetch or update an Asset Report.
+    pub asset_report_token: String,
+
+    /// A unique ID identifying an Asset Report. Like all Plaid identifiers,
+    /// this ID is case sensitive.
+    pub asset_report_id: String,
+
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
+
+/// The response from performing an `asset_report_get` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AssetReportGetResponse {
+    /// An object representing an Asset Report.
+    pub report: AssetReport,
+
+    /// If the Asset Report generation was successful but identity information
+    /// cannot be returned, this array will contain information about the
+    /// errors causing identity information to be missing.
+    #[serde(default)]
+    pub warnings: Vec<AssetReportWarning>,
+
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
+
+/// An Asset Report.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AssetReport {
+    /// A unique ID identifying an Asset Report. Like all Plaid identifiers,
+    /// this ID is case sensitive.
+    pub asset_report_id: String,
+
+    /// An identifier you determine and submit for the Asset Report.
+    pub client_report_id: Option<String>,
+
+    /// The date and time when the Asset Report was created, in [ISO 8601]
+    /// format.
+    ///
+    /// [ISO 8601]: https://wikipedia.org/wiki/ISO_8601
+    pub date_generated: chrono::DateTime<chrono::FixedOffset>,
+
+    /// The duration of transaction history you requested.
+    pub days_requested: u32,
+
+    /// The user object provided when the Asset Report was created.
+    pub user: AssetReportUser,
+
+    /// Data returned by Plaid about each of the Items included in the Asset
+    /// Report.
+    #[serde(default)]
+    pub items: Vec<AssetReportItem>,
+}
+
+impl AssetReport {
+    /// The header row of the CSV written by `write_csv`.
+    pub const CSV_COLUMNS: [&'static str; 15] = [
+        "item_id",
+        "institution_name",
+        "account_id",
+        "account_name",
+        "account_mask",
+        "account_type",
+        "balance_available",
+        "balance_current",
+        "balance_currency",
+        "transaction_id",
+        "transaction_date",
+        "transaction_description",
+        "transaction_amount",
+        "transaction_currency",
+        "transaction_pending",
+    ];
+
+    /// Writes the accounts and transactions of the report as [RFC 4180] CSV,
+    /// starting with a header row of `CSV_COLUMNS`.
+    ///
+    /// Each transaction is a row, repeating the columns of its Item and
+    /// account; an account without transactions is a single row with empty
+    /// transaction columns. Absent values are empty. The description of a
+    /// transaction is its `name` if insights were included, otherwise its
+    /// `original_description`.
+    ///
+    /// Text fields starting with `=`, `+`, `-`, `@`, a tab or a carriage
+    /// return are prefixed with `'`, so that spreadsheet applications don't
+    /// evaluate them as formulas. Numeric fields, such as negative amounts,
+    /// are written as is.
+    ///
+    /// [RFC 4180]: https://tools.ietf.org/html/rfc4180
+    pub fn write_csv<W>(&self, mut writer: W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        write_csv_row(&mut writer, &Self::CSV_COLUMNS)?;
+
+        for item in &self.items {
+            for report_account in &item.accounts {
+                let account = &report_account.account;
+                let balances = &account.balances;
+                let account_columns = [
+                    text(&item.item_id),
+                    text(&item.institution_name),
+                    text(&account.account_id),
+                    text(&account.name),
+                    text(account.mask.as_deref().unwrap_or_default()),
+                    account.ty.as_str().to_string(),
+                    optional(balances.available),
+                    optional(balances.current),
+                    text(balances.currency_code().unwrap_or_default()),
+                ];
+
+                if report_account.transactions.is_empty() {
+                    let mut row = account_columns.to_vec();
+                    row.resize(Self::CSV_COLUMNS.len(), String::new());
+                    write_csv_row(&mut writer, &row)?;
+                }
+                for transaction in &report_account.transactions {
+                    let mut row = account_columns.to_vec();
+                    row.extend(vec![
+                        text(&transaction.transaction_id),
+                        transaction.date.to_string(),
+                        text(
+                            transaction
+                                .name
+                                .as_deref()
+                                .or(transaction.original_description.as_deref())
+                                .unwrap_or_default(),
+                        ),
+                        transaction.amount.to_string(),
+                        text(
+                            transaction
+                                .iso_currency_code
+                                .as_deref()
+                                .or(transaction.unofficial_currency_code.as_deref())
+                                .unwrap_or_default(),
+                        ),
+                        transaction.pending.to_string(),
+                    ]);
+                    write_csv_row(&mut writer, &row)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The report as CSV, see `write_csv`.
+    pub fn to_csv(&self) -> String {
+        let mut csv = Vec::new();
+        self.write_csv(&mut csv)
+            .expect("writing to a `Vec` cannot fail");
+        String::from_utf8(csv).expect("the CSV is built from strings")
+    }
+}
+
+/// Prefixes text that spreadsheet applications would evaluate as a formula
+/// with `'`.
+fn text(value: &str) -> String {
+    if value.starts_with(&['=', '+', '-', '@', '\t', '\r'][..]) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    }
+}
+
+fn optional(value: Option<f64>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+/// Writes a CSV row, quoting the fields that contain separators or quotes.
+fn write_csv_row<W, S>(writer: &mut W, fields: &[S]) -> io::Result<()>
+where
+    W: io::Write,
+    S: AsRef<str>,
+{
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            writer.write_all(b",")?;
+        }
+        let field = field.as_ref();
+        if field.contains(&[',', '"', '\n', '\r'][..]) {
+            write!(writer, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            writer.write_all(field.as_bytes())?;
+        }
+    }
+    writer.write_all(b"\r\n")
+}
+
+/// An Item included in an Asset Report.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AssetReportItem {
+    /// The `item_id` of the Item.
+    pub item_id: String,
+
+    /// The full financial institution name associated with the Item.
+    pub institution_name: String,
+
+    /// The ID of the financial institution associated with the Item.
+    pub institution_id: String,
+
+    /// The date and time when this Item’s data was last retrieved from the
+    /// financial institution, in [ISO 8601] format.
+    ///
+    /// [ISO 8601]: https://wikipedia.org/wiki/ISO_8601
+    pub date_last_updated: chrono::DateTime<chrono::FixedOffset>,
+
+    /// Data about each of the accounts open on the Item.
+    #[serde(default)]
+    pub accounts: Vec<AssetReportAccount>,
+}
+
+/// An account included in an Asset Report.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AssetReportAccount {
+    /// The account, including the Assets-only `historical_balances`, `owners`
+    /// and `days_available` fields.
+    #[serde(flatten)]
+    pub account: super::Account,
+
+    /// Transaction history associated with the account.
+    #[serde(default)]
+    pub transactions: Vec<AssetReportTransaction>,
+}
+
+// TODO: use tagged enum instead of both currency fields
+/// A transaction on an account included in an Asset Report.
+///
+/// The insight fields (`name`, `merchant_name`, `category`,
+/// `date_transacted` and `account_owner`) are only populated when the report
+/// is retrieved with `include_insights` set.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AssetReportTransaction {
+    /// The unique ID of the transaction.
+    pub transaction_id: String,
+
+    /// The ID of the account in which this transaction occurred.
+    pub account_id: String,
+
+    /// The settled value of the transaction, denominated in the account's
+    /// currency. Positive values when money moves out of the account; negative
+    /// values when money moves in.
+    #[serde(deserialize_with = "super::serde_utils::num_or_str::deserialize")]
+    pub amount: f64,
+
+    // TODO: use ISO 4217 library
+    /// The [ISO 4217] currency code of the transaction.
+    ///
+    /// Always null if `unofficial_currency_code` is non-null.
+    ///
+    /// [ISO 4217]: https://en.wikipedia.org/wiki/ISO_4217
+    pub iso_currency_code: Option<String>,
+
+    /// The unofficial currency code associated with the transaction.
+    ///
+    /// Always null if `iso_currency_code` is non-null.
+    pub unofficial_currency_code: Option<String>,
+
+    /// The string returned by the financial institution to describe the
+    /// transaction.
+    pub original_description: Option<String>,
+
+    /// For pending transactions, the date that the transaction occurred; for
+    /// posted transactions, the date that the transaction posted.
+    #[serde(with = "super::serde_utils::plaid_date")]
+    pub date: chrono::NaiveDate,
+
+    /// When `true`, identifies the transaction as pending or unsettled.
+    pub pending: bool,
+
+    /// The merchant name or transaction description.
+    ///
+    /// Insight field.
+    pub name: Option<String>,
+
+    /// The merchant name, as extracted by Plaid from the `name` field.
+    ///
+    /// Insight field.
+    pub merchant_name: Option<String>,
+
+    /// The legacy category of the transaction.
+    ///
+    /// Insight field.
+    #[serde(flatten)]
+    pub category: super::Category,
+
+    /// The date on which the transaction took place, in ISO 8601 format.
+    ///
+    /// Insight field.
+    #[serde(default, with = "super::serde_utils::plaid_date::option")]
+    pub date_transacted: Option<chrono::NaiveDate>,
+
+    /// The name of the account owner.
+    ///
+    /// Insight field.
+    pub account_owner: Option<String>,
+}
+
+/// A warning about an Asset Report.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AssetReportWarning {
+    /// The warning type, which will always be `ASSET_REPORT_WARNING`.
+    pub warning_type: String,
+
+    /// The warning code identifies a specific kind of warning, e.g.
+    /// `OWNERS_UNAVAILABLE`.
+    pub warning_code: String,
+
+    /// An error object and associated `item_id` used to identify a specific
+    /// Item and error when a batch operation operating on multiple Items has
+    /// encountered an error in one of the Items.
+    pub cause: Option<crate::ApiErrorCause>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn transaction(insights: bool) -> serde_json::Value {
+        let mut transaction = json!({
+            "account_id": "eG7pNLjknrFpWvP7Dkbdf3Pq6GVBPKTaQJK5v",
+            "amount": 38.9,
+            "date": "2020-05-28",
+            "iso_currency_code": "USD",
+            "original_description": "Trader Joes 1234",
+            "pending": false,
+            "transaction_id": "3mg4qvJxz1cjdEL3mLXKcL8TBVEj7AcP5x6Ma",
+            "unofficial_currency_code": null
+        });
+        if insights {
+            let fields = json!({
+                "account_owner": null,
+                "category": ["Shops", "Supermarkets and Groceries"],
+                "category_id": "19046000",
+                "date_transacted": "2020-05-27",
+                "merchant_name": "Trader Joe's",
+                "name": "Trader Joes"
+            });
+            for (key, value) in fields.as_object().unwrap() {
+                transaction[key] = value.clone();
+            }
+        }
+        transaction
+    }
+
+    fn report(insights: bool) -> serde_json::Value {
+        json!({
+            "report": {
+                "asset_report_id": "bf3a0490-344c-4620-a219-2693162e4b1d",
+                "client_report_id": "123abc",
+                "date_generated": "2020-06-05T22:47:53Z",
+                "days_requested": 3,
+                "items": [{
+                    "accounts": [{
+                        "account_id": "eG7pNLjknrFpWvP7Dkbdf3Pq6GVBPKTaQJK5v",
+                        "balances": {
+                            "available": 200,
+                            "current": 210,
+                            "iso_currency_code": "USD",
+                            "limit": null,
+                            "unofficial_currency_code": null
+                        },
+                        "days_available": 3,
+                        "historical_balances": [],
+                        "mask": "1111",
+                        "name": "Plaid Saving",
+                        "official_name": "Plaid Silver Standard 0.1% Interest Saving",
+                        "owners": [],
+                        "subtype": "savings",
+                        "transactions": [transaction(insights)],
+                        "type": "depository"
+                    }],
+                    "date_last_updated": "2020-06-05T22:47:52Z",
+                    "institution_id": "ins_3",
+                    "institution_name": "Chase",
+                    "item_id": "eVBnVMp7zdTJLkRNr33Rs6zr7KNJqBFL9DrE6"
+                }],
+                "user": {
+                    "client_user_id": "123456789",
+                    "email": "accounts@plaid.com",
+                    "first_name": "Alberta",
+                    "last_name": "Charleson",
+                    "middle_name": "Bobbeth",
+                    "phone_number": "111-222-3333",
+                    "ssn": "123-45-6789"
+                }
+            },
+            "request_id": "eYupqX1mZkEuQRx",
+            "warnings": []
+        })
+    }
+
+    #[test]
+    fn can_deserialize_asset_report_with_insights() {
+        let response: AssetReportGetResponse = serde_json::from_value(report(true)).unwrap();
+        let account = &response.report.items[0].accounts[0];
+        let transaction = &account.transactions[0];
+
+        assert_eq!(
+            account.account.account_id,
+            "eG7pNLjknrFpWvP7Dkbdf3Pq6GVBPKTaQJK5v"
+        );
+        assert_eq!(transaction.amount, 38.9);
+        assert_eq!(transaction.merchant_name.as_deref(), Some("Trader Joe's"));
+        assert_eq!(
+            transaction.category.hierarchy,
+            vec!["Shops", "Supermarkets and Groceries"]
+        );
+        assert_eq!(
+            transaction.date_transacted,
+            Some(chrono::NaiveDate::from_ymd_opt(2020, 5, 27).unwrap())
+        );
+    }
+
+    #[test]
+    fn can_write_asset_report_as_csv() {
+        let mut value = report(true);
+        value["report"]["items"][0]["institution_name"] = json!("Chase, \"N.A.\"");
+        let transaction = &mut value["report"]["items"][0]["accounts"][0]["transactions"][0];
+        transaction["name"] = json!("=1+2");
+        transaction["amount"] = json!(-38.9);
+        let mut account = value["report"]["items"][0]["accounts"][0].clone();
+        account["account_id"] = json!("9LlX1mPLbPtxBNvwyzPRtnL5Tq6RQLfyjP16j");
+        account["transactions"] = json!([]);
+        value["report"]["items"][0]["accounts"]
+            .as_array_mut()
+            .unwrap()
+            .push(account);
+        let response: AssetReportGetResponse = serde_json::from_value(value).unwrap();
+
+        let csv = response.report.to_csv();
+        let rows: Vec<_> = csv.split_terminator("\r\n").collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], AssetReport::CSV_COLUMNS.join(","));
+        assert_eq!(
+            rows[1],
+            "eVBnVMp7zdTJLkRNr33Rs6zr7KNJqBFL9DrE6,\"Chase, \"\"N.A.\"\"\",\
+             eG7pNLjknrFpWvP7Dkbdf3Pq6GVBPKTaQJK5v,Plaid Saving,1111,depository,200,210,USD,\
+             3mg4qvJxz1cjdEL3mLXKcL8TBVEj7AcP5x6Ma,2020-05-28,'=1+2,-38.9,USD,false"
+        );
+        assert!(rows[2].starts_with(
+            "eVBnVMp7zdTJLkRNr33Rs6zr7KNJqBFL9DrE6,\"Chase, \"\"N.A.\"\"\",\
+             9LlX1mPLbPtxBNvwyzPRtnL5Tq6RQLfyjP16j,"
+        ));
+        assert!(rows[2].ends_with(",USD,,,,,,"));
+    }
+
+    #[test]
+    fn can_deserialize_string_amounts() {
+        let mut value = transaction(false);
+        value["amount"] = json!("-38.9");
+        let transaction: AssetReportTransaction = serde_json::from_value(value).unwrap();
+        assert_eq!(transaction.amount, -38.9);
+    }
+
+    #[test]
+    fn can_deserialize_asset_report_without_insights() {
+        let response: AssetReportGetResponse = serde_json::from_value(report(false)).unwrap();
+        let transaction = &response.report.items[0].accounts[0].transactions[0];
+
+        assert_eq!(
+            transaction.original_description.as_deref(),
+            Some("Trader Joes 1234")
+        );
+        assert!(transaction.merchant_name.is_none());
+        assert!(transaction.category.hierarchy.is_empty());
+        assert!(transaction.category.category_id.is_none());
+    }
+}
@@ -15,6 +15,7 @@ pub struct AuthRequestOptions {
 
 /// The response from performing an `auth` request.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct AuthResponse {
     /// The accounts for which numbers are being retrieved.
     #[serde(default)]
@@ -33,6 +34,38 @@ pub struct AuthResponse {
     pub request_id: String,
 }
 
+impl AuthResponse {
+    /// The ACH numbers of the account with the given `account_id`, if any.
+    pub fn ach_numbers_for(&self, account_id: &str) -> Option<&AchAccountNumbers> {
+        self.numbers.ach.iter().find(|n| n.account_id == account_id)
+    }
+
+    /// The EFT numbers of the account with the given `account_id`, if any.
+    pub fn eft_numbers_for(&self, account_id: &str) -> Option<&EftAccountNumbers> {
+        self.numbers.eft.iter().find(|n| n.account_id == account_id)
+    }
+
+    /// The international numbers of the account with the given `account_id`,
+    /// if any.
+    pub fn international_numbers_for(
+        &self,
+        account_id: &str,
+    ) -> Option<&InternationalAccountNumbers> {
+        self.numbers
+            .international
+            .iter()
+            .find(|n| n.account_id == account_id)
+    }
+
+    /// The BACS numbers of the account with the given `account_id`, if any.
+    pub fn bacs_numbers_for(&self, account_id: &str) -> Option<&BacsAccountNumbers> {
+        self.numbers
+            .bacs
+            .iter()
+            .find(|n| n.account_id == account_id)
+    }
+}
+
 /// An object containing identifying numbers used for making electronic
 /// transfers to and from the accounts.
 ///
@@ -41,6 +74,7 @@ pub struct AuthResponse {
 /// If a particular identifying number type is not used by any accounts for
 /// which data has been requested, the `Vec` for that type will be empty.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct AccountNumbers {
     /// A list of ACH numbers identifying accounts.
     #[serde(default)]
@@ -61,6 +95,7 @@ pub struct AccountNumbers {
 
 /// The numbers identifying an ACH account.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct AchAccountNumbers {
     /// The Plaid account ID associated with the account numbers
     pub account_id: String,
@@ -77,6 +112,7 @@ pub struct AchAccountNumbers {
 
 /// The numbers identifying an EFT account.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct EftAccountNumbers {
     /// The Plaid account ID associated with the account numbers
     pub account_id: String,
@@ -93,6 +129,7 @@ pub struct EftAccountNumbers {
 
 /// The numbers identifying an IBAN account.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InternationalAccountNumbers {
     /// The Plaid account ID associated with the account numbers
     pub account_id: String,
@@ -106,6 +143,7 @@ pub struct InternationalAccountNumbers {
 
 /// The numbers identifying a BACS account.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct BacsAccountNumbers {
     /// The Plaid account ID associated with the account numbers
     pub account_id: String,
@@ -116,3 +154,49 @@ pub struct BacsAccountNumbers {
     /// The BACS sort code for the account
     pub sort_code: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn can_get_numbers_for_account() {
+        let response: AuthResponse = serde_json::from_value(json!({
+            "accounts": [],
+            "numbers": {
+                "ach": [
+                    {
+                        "account": "9900009606",
+                        "account_id": "vzeNDwK7KQIm4yEog683uElbp9GRLEFXGK98D",
+                        "routing": "011401533",
+                        "wire_routing": "021000021"
+                    },
+                    {
+                        "account": "1111222233330000",
+                        "account_id": "6Myq63K1KDSe3lBwp7K1fnEbNGLV4nSxalVdW",
+                        "routing": "011401533",
+                        "wire_routing": null
+                    }
+                ],
+                "eft": [],
+                "international": [],
+                "bacs": []
+            },
+            "item": fixtures::item(),
+            "request_id": "m8MDnv9okwxFNBV"
+        }))
+        .unwrap();
+
+        let ach = response
+            .ach_numbers_for("6Myq63K1KDSe3lBwp7K1fnEbNGLV4nSxalVdW")
+            .unwrap();
+        assert_eq!(ach.account, "1111222233330000");
+        assert!(response.ach_numbers_for("unknown").is_none());
+        assert!(response
+            .bacs_numbers_for("6Myq63K1KDSe3lBwp7K1fnEbNGLV4nSxalVdW")
+            .is_none());
+    }
+}
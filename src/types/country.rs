@@ -0,0 +1,19 @@
+//! Country types.
+
+use serde::{Deserialize, Serialize};
+
+/// Supported countries in [ISO 3166-1 alpha-2] format.
+///
+/// [ISO 3166-1 alpha-2]: https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+#[serde(rename_all = "UPPERCASE")]
+#[allow(missing_docs)]
+pub enum SupportedCountry {
+    US,
+    CA,
+    ES,
+    FR,
+    GB,
+    IE,
+    NL,
+}
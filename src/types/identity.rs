@@ -0,0 +1,14 @@
+//! Identity types.
+
+use serde::{Deserialize, Serialize};
+
+/// Options for the `identity` request.
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct IdentityRequestOptions {
+    /// A list of `account_ids` to retrieve for the Item.
+    ///
+    /// *Note*: An error will be returned if a provided `account_id` is not
+    /// associated with the Item.
+    #[serde(default, with = "super::serde_utils::default_on_null")]
+    pub account_ids: Vec<String>,
+}
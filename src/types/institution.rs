@@ -0,0 +1,518 @@
+//! Institution types.
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use super::{Product, StandingOrderMetadata};
+
+/// The minimum success rate for a request type to be considered healthy by
+/// `InstitutionStatus::overall_health`.
+pub const HEALTHY_SUCCESS_RATE: f64 = 0.9;
+
+/// The minimum success rate for a request type to be considered degraded,
+/// rather than down, by `InstitutionStatus::overall_health`.
+pub const DEGRADED_SUCCESS_RATE: f64 = 0.5;
+
+/// Options for the `institution` request.
+///
+/// Every option defaults to `false`; set only the ones needed, e.g.:
+///
+/// ```
+/// let options = plaid::InstitutionRequestOptions {
+///     include_optional_metadata: true,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct InstitutionRequestOptions {
+    /// When `true`, return an institution's logo, brand color, and URL.
+    #[serde(default)]
+    pub include_optional_metadata: bool,
+
+    /// If `true`, the response will include status information about the
+    /// institution.
+    ///
+    /// *Note*: In Sandbox, the status is always `null`.
+    #[serde(default)]
+    pub include_status: bool,
+
+    /// When `true`, returns metadata related to the Auth product indicating
+    /// which auth methods are supported.
+    #[serde(default)]
+    pub include_auth_metadata: bool,
+
+    /// When `true`, returns metadata related to the Payment Initiation
+    /// product indicating which payment configurations are supported.
+    #[serde(default)]
+    pub include_payment_initiation_metadata: bool,
+}
+
+/// The response from performing an `institution` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InstitutionResponse {
+    /// Details relating to a specific financial institution.
+    pub institution: Institution,
+
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
+
+/// The response from performing an `institutions` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InstitutionsGetResponse {
+    /// A list of institutions.
+    pub institutions: Vec<Institution>,
+
+    /// The total number of institutions available via this endpoint.
+    pub total: u32,
+
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
+
+/// The response from performing an `institutions_search` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InstitutionsSearchResponse {
+    /// An array of institutions matching the search criteria.
+    pub institutions: Vec<Institution>,
+
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
+
+/// Details relating to a specific financial institution.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Institution {
+    /// Unique identifier for the institution.
+    pub institution_id: String,
+
+    /// The official name of the institution.
+    pub name: String,
+
+    /// A list of the Plaid products supported by the institution.
+    pub products: Vec<Product>,
+
+    // TODO: use `SupportedCountry` once it covers every country Plaid returns
+    /// A list of the country codes supported by the institution.
+    pub country_codes: Vec<String>,
+
+    /// The URL for the institution's website.
+    pub url: Option<String>,
+
+    /// Hexadecimal representation of the primary color used by the
+    /// institution.
+    pub primary_color: Option<String>,
+
+    /// Base64 encoded representation of the institution's logo.
+    pub logo: Option<String>,
+
+    /// A partial list of routing numbers associated with the institution.
+    #[serde(default, with = "super::serde_utils::default_on_null")]
+    pub routing_numbers: Vec<String>,
+
+    /// Indicates that the institution has a mandatory OAuth login flow.
+    pub oauth: bool,
+
+    /// The status of an institution is determined by the health of its Item
+    /// logins, Transactions updates, Investments updates, Liabilities
+    /// updates, Auth requests, Balance requests, Identity requests,
+    /// Investments requests, and Liabilities requests.
+    ///
+    /// Only present if the request was made with `include_status` set.
+    pub status: Option<InstitutionStatus>,
+
+    /// Metadata that captures information about the Auth features of an
+    /// institution.
+    ///
+    /// Only present if the request was made with `include_auth_metadata`
+    /// set.
+    pub auth_metadata: Option<AuthMetadata>,
+
+    /// Metadata that captures what specific payment configurations an
+    /// institution supports when making Payment Initiation requests.
+    ///
+    /// Only present if the request was made with
+    /// `include_payment_initiation_metadata` set.
+    pub payment_initiation_metadata: Option<PaymentInitiationMetadata>,
+}
+
+impl Institution {
+    /// Returns `true` if the institution supports the given product.
+    pub fn supports(&self, product: Product) -> bool {
+        self.products.contains(&product)
+    }
+
+    /// The decoded image of the institution's logo, if present and valid
+    /// base64.
+    pub fn decoded_logo(&self) -> Option<Vec<u8>> {
+        let logo = self.logo.as_ref()?;
+        base64::engine::general_purpose::STANDARD.decode(logo).ok()
+    }
+
+    /// Returns `true` if the institution supports Instant Auth.
+    ///
+    /// Like the other capability checks, this is `false` if the
+    /// institution's metadata was not requested or not returned.
+    pub fn supports_instant_auth(&self) -> bool {
+        self.auth_methods()
+            .is_some_and(|methods| methods.instant_auth)
+    }
+
+    /// Returns `true` if the institution supports Instant Match.
+    pub fn supports_instant_match(&self) -> bool {
+        self.auth_methods()
+            .is_some_and(|methods| methods.instant_match)
+    }
+
+    /// Returns `true` if the institution supports Automated Micro-deposits.
+    pub fn supports_automated_micro_deposits(&self) -> bool {
+        self.auth_methods()
+            .is_some_and(|methods| methods.automated_micro_deposits)
+    }
+
+    /// Returns `true` if the institution supports Instant Micro-deposits.
+    pub fn supports_instant_micro_deposits(&self) -> bool {
+        self.auth_methods()
+            .is_some_and(|methods| methods.instant_micro_deposits)
+    }
+
+    /// Returns `true` if the institution supports international Payment
+    /// Initiation payments.
+    pub fn supports_international_payments(&self) -> bool {
+        self.payment_initiation_metadata
+            .as_ref()
+            .is_some_and(|metadata| metadata.supports_international_payments)
+    }
+
+    /// Returns `true` if the institution supports SEPA Instant Credit
+    /// Transfers.
+    pub fn supports_sepa_instant(&self) -> bool {
+        self.payment_initiation_metadata
+            .as_ref()
+            .is_some_and(|metadata| metadata.supports_sepa_instant)
+    }
+
+    /// Returns `true` if the institution supports returning refund details
+    /// when initiating a payment.
+    pub fn supports_refund_details(&self) -> bool {
+        self.payment_initiation_metadata
+            .as_ref()
+            .is_some_and(|metadata| metadata.supports_refund_details)
+    }
+
+    fn auth_methods(&self) -> Option<&AuthSupportedMethods> {
+        self.auth_metadata.as_ref()?.supported_methods.as_ref()
+    }
+}
+
+/// Metadata about the Auth features of an `Institution`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AuthMetadata {
+    /// Metadata specifically related to which auth methods an institution
+    /// supports.
+    pub supported_methods: Option<AuthSupportedMethods>,
+}
+
+/// The Auth methods supported by an `Institution`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AuthSupportedMethods {
+    /// Indicates if instant auth is supported.
+    pub instant_auth: bool,
+
+    /// Indicates if instant match is supported.
+    pub instant_match: bool,
+
+    /// Indicates if automated microdeposits are supported.
+    pub automated_micro_deposits: bool,
+
+    /// Indicates if instant microdeposits are supported.
+    #[serde(default)]
+    pub instant_micro_deposits: bool,
+}
+
+/// The Payment Initiation configurations supported by an `Institution`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaymentInitiationMetadata {
+    /// Indicates whether the institution supports payments from a different
+    /// country.
+    pub supports_international_payments: bool,
+
+    /// Indicates whether the institution supports SEPA Instant payments.
+    pub supports_sepa_instant: bool,
+
+    /// A mapping of currency to maximum payment amount (denominated in the
+    /// smallest unit of currency) supported by the institution.
+    #[serde(default)]
+    pub maximum_payment_amount: std::collections::HashMap<String, String>,
+
+    /// Indicates whether the institution supports returning refund details
+    /// when initiating a payment.
+    pub supports_refund_details: bool,
+
+    /// The standing order configurations supported by the institution.
+    pub standing_order_metadata: Option<StandingOrderMetadata>,
+}
+
+/// The health of an `Institution`, as synthesized by
+/// `InstitutionStatus::overall_health`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HealthLevel {
+    /// Every reported request type succeeds at least `HEALTHY_SUCCESS_RATE`
+    /// of the time.
+    Healthy,
+    /// Some request type succeeds less than `HEALTHY_SUCCESS_RATE`, but at
+    /// least `DEGRADED_SUCCESS_RATE`, of the time.
+    Degraded,
+    /// Some request type succeeds less than `DEGRADED_SUCCESS_RATE` of the
+    /// time.
+    Down,
+}
+
+/// The status of an `Institution`, for each type of request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct InstitutionStatus {
+    /// A representation of the status health of a request type.
+    pub item_logins: Option<RequestStatus>,
+
+    /// A representation of the status health of a request type.
+    pub transactions_updates: Option<RequestStatus>,
+
+    /// A representation of the status health of a request type.
+    pub auth: Option<RequestStatus>,
+
+    /// A representation of the status health of a request type.
+    pub identity: Option<RequestStatus>,
+
+    /// A representation of the status health of a request type.
+    pub investments_updates: Option<RequestStatus>,
+
+    /// A representation of the status health of a request type.
+    pub liabilities_updates: Option<RequestStatus>,
+
+    /// A representation of the status health of a request type.
+    pub liabilities: Option<RequestStatus>,
+
+    /// A representation of the status health of a request type.
+    pub investments: Option<RequestStatus>,
+}
+
+impl InstitutionStatus {
+    /// The status of each request type reported for the institution.
+    pub fn request_statuses(&self) -> impl Iterator<Item = &RequestStatus> {
+        vec![
+            &self.item_logins,
+            &self.transactions_updates,
+            &self.auth,
+            &self.identity,
+            &self.investments_updates,
+            &self.liabilities_updates,
+            &self.liabilities,
+            &self.investments,
+        ]
+        .into_iter()
+        .filter_map(Option::as_ref)
+    }
+
+    /// The health of the institution, determined by the lowest
+    /// `Breakdown::success` rate across the reported request types.
+    ///
+    /// An institution with no reported request types is `Healthy`.
+    pub fn overall_health(&self) -> HealthLevel {
+        let success = self
+            .request_statuses()
+            .map(|status| status.breakdown.success)
+            .fold(1.0, f64::min);
+
+        if success >= HEALTHY_SUCCESS_RATE {
+            HealthLevel::Healthy
+        } else if success >= DEGRADED_SUCCESS_RATE {
+            HealthLevel::Degraded
+        } else {
+            HealthLevel::Down
+        }
+    }
+}
+
+/// A representation of the status health of a request type.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RequestStatus {
+    /// This field is deprecated in favor of the `breakdown` object, which
+    /// provides more granular institution health data.
+    #[deprecated = "use `breakdown` instead"]
+    pub status: Option<String>,
+
+    /// [ISO 8601] formatted timestamp of the last status change for the
+    /// institution.
+    ///
+    /// [ISO 8601]: https://wikipedia.org/wiki/ISO_8601
+    pub last_status_change: Option<chrono::DateTime<chrono::FixedOffset>>,
+
+    /// A detailed breakdown of the institution's performance for a request
+    /// type.
+    pub breakdown: Breakdown,
+}
+
+impl RequestStatus {
+    /// Returns `true` if the request type succeeds at least `threshold` of
+    /// the time, where `threshold` is between `0.0` and `1.0`.
+    pub fn is_healthy(&self, threshold: f64) -> bool {
+        self.breakdown.success >= threshold
+    }
+}
+
+/// A detailed breakdown of an institution's performance for a request type.
+///
+/// The success and error rates add up to `1.0`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Breakdown {
+    /// The percentage of login attempts that are successful, expressed as a
+    /// decimal.
+    pub success: f64,
+
+    /// The percentage of logins that are failing due to an internal Plaid
+    /// issue, expressed as a decimal.
+    pub error_plaid: f64,
+
+    /// The percentage of logins that are failing due to an issue in the
+    /// institution's system, expressed as a decimal.
+    pub error_institution: f64,
+
+    /// How frequently data for subscription products like Investments,
+    /// Transactions, and Liabilities, is being refreshed, relative to the
+    /// institution's normal scheduling, e.g. `"NORMAL"`.
+    pub refresh_interval: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    fn request_status(success: f64) -> serde_json::Value {
+        json!({
+            "status": "HEALTHY",
+            "last_status_change": "2019-02-15T15:53:00Z",
+            "breakdown": {
+                "success": success,
+                "error_plaid": 0.0,
+                "error_institution": 1.0 - success,
+                "refresh_interval": "NORMAL"
+            }
+        })
+    }
+
+    fn institution(auth_success: f64) -> serde_json::Value {
+        let mut institution = fixtures::institution();
+        institution["status"] = json!({
+            "item_logins": request_status(0.98),
+            "transactions_updates": request_status(0.95),
+            "auth": request_status(auth_success),
+            "identity": null,
+            "investments_updates": null,
+            "liabilities_updates": null,
+            "liabilities": null,
+            "investments": null
+        });
+        institution
+    }
+
+    #[test]
+    fn can_get_overall_health() {
+        let health = |auth_success| {
+            let institution: Institution =
+                serde_json::from_value(institution(auth_success)).unwrap();
+            institution.status.unwrap().overall_health()
+        };
+
+        assert_eq!(health(0.92), HealthLevel::Healthy);
+        assert_eq!(health(0.6), HealthLevel::Degraded);
+        assert_eq!(health(0.1), HealthLevel::Down);
+    }
+
+    #[test]
+    fn can_check_supported_products() {
+        let institution: Institution = serde_json::from_value(institution(0.9)).unwrap();
+        assert!(institution.supports(Product::Auth));
+        assert!(!institution.supports(Product::Investments));
+    }
+
+    #[test]
+    fn can_check_capabilities() {
+        let mut value = institution(0.9);
+        let institution: Institution = serde_json::from_value(value.clone()).unwrap();
+        assert!(!institution.supports_instant_auth());
+        assert!(!institution.supports_sepa_instant());
+
+        value["auth_metadata"] = json!({
+            "supported_methods": {
+                "instant_auth": true,
+                "instant_match": false,
+                "automated_micro_deposits": true
+            }
+        });
+        value["payment_initiation_metadata"] = json!({
+            "supports_international_payments": false,
+            "supports_sepa_instant": true,
+            "maximum_payment_amount": { "EUR": "1000000" },
+            "supports_refund_details": true,
+            "standing_order_metadata": null
+        });
+        let institution: Institution = serde_json::from_value(value).unwrap();
+        assert!(institution.supports_instant_auth());
+        assert!(!institution.supports_instant_match());
+        assert!(institution.supports_automated_micro_deposits());
+        assert!(!institution.supports_instant_micro_deposits());
+        assert!(!institution.supports_international_payments());
+        assert!(institution.supports_sepa_instant());
+        assert!(institution.supports_refund_details());
+    }
+
+    #[test]
+    fn can_decode_logo() {
+        let mut value = institution(0.9);
+        let institution: Institution = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(institution.decoded_logo(), None);
+
+        value["logo"] = json!("iVBORw0KGgo=");
+        let institution: Institution = serde_json::from_value(value).unwrap();
+        assert_eq!(
+            institution.decoded_logo().unwrap(),
+            b"\x89PNG\r\n\x1a\n".to_vec()
+        );
+    }
+
+    #[test]
+    fn options_default_to_false() {
+        let options = InstitutionRequestOptions {
+            include_optional_metadata: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            json!({
+                "include_optional_metadata": true,
+                "include_status": false,
+                "include_auth_metadata": false,
+                "include_payment_initiation_metadata": false
+            })
+        );
+    }
+
+    #[test]
+    fn can_check_request_status_health() {
+        let status: RequestStatus = serde_json::from_value(request_status(0.8)).unwrap();
+        assert!(status.is_healthy(0.75));
+        assert!(!status.is_healthy(0.85));
+    }
+}
@@ -0,0 +1,13 @@
+//! Investments types.
+
+use serde::{Deserialize, Serialize};
+
+/// The response from performing an `investments_refresh` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InvestmentsRefreshResponse {
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
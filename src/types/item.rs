@@ -1,12 +1,15 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Description of the kind of webhook
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[serde(tag = "webhook_code")]
 pub enum ItemWebhookCode {
     /// Fired when an error is encountered with an Item. The error can be resolved by having the user go through Link’s update mode.
     Error,
+    /// Fired when an Item that required the user to log in again, with an `ITEM_LOGIN_REQUIRED` error, starts working again without any action from the user.
+    LoginRepaired,
     /// Fired when Plaid detects a new account for Items created or updated with Account Select v2. Upon receiving this webhook, you can prompt your users to share new accounts with you through Account Select v2 update mode.
     NewAccountsAvailable,
     /// Fired when an Item’s access consent is expiring in 7 days. Some Items have explicit expiration times and we try to relay this when possible to reduce service disruption. This can be resolved by having the user go through Link’s update mode.
@@ -24,7 +27,7 @@ pub enum ItemWebhookCode {
 }
 
 /// Webhooks are used to communicate changes to an `Item`, such as an updated webhook, or errors encountered with an `Item`. The error typically requires user action to resolve, such as when a user changes their password. All `Item` webhooks have a `webhook_type` of `ITEM`.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ItemWebhook {
     /// Description of the kind of webhook
     #[serde(flatten)]
@@ -32,3 +35,212 @@ pub struct ItemWebhook {
     /// The item_id of the Item associated with this webhook, warning, or error
     pub item_id: String,
 }
+
+/// The response from performing an `item_get` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ItemGetResponse {
+    /// Metadata about the Item.
+    pub item: super::Item,
+
+    /// Information about the last successful and failed updates of the Item,
+    /// and the last webhook sent for it, if any.
+    pub status: Option<ItemStatus>,
+
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
+
+/// The response from performing an `item_remove` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ItemRemoveResponse {
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
+
+/// The update status of an `Item`, per product.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ItemStatus {
+    /// The update status of the Item's investments.
+    pub investments: Option<ProductStatus>,
+
+    /// The update status of the Item's transactions.
+    pub transactions: Option<ProductStatus>,
+
+    /// The last webhook sent for the Item.
+    pub last_webhook: Option<LastWebhook>,
+}
+
+impl ItemStatus {
+    /// The time of the last successful update of the Item's transactions.
+    pub fn transactions_last_update(&self) -> Option<DateTime<Utc>> {
+        self.transactions
+            .as_ref()
+            .and_then(|status| status.last_successful_update)
+    }
+
+    /// The time of the last successful update of the Item's investments.
+    pub fn investments_last_update(&self) -> Option<DateTime<Utc>> {
+        self.investments
+            .as_ref()
+            .and_then(|status| status.last_successful_update)
+    }
+
+    /// Returns `true` if no product's last update failed, i.e. every product
+    /// either never failed to update or has succeeded since.
+    pub fn is_healthy(&self) -> bool {
+        self.investments
+            .iter()
+            .chain(&self.transactions)
+            .all(|status| !status.is_failing())
+    }
+}
+
+/// The update status of a product of an `Item`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default)]
+pub struct ProductStatus {
+    /// The time of the last successful update of the product.
+    pub last_successful_update: Option<DateTime<Utc>>,
+
+    /// The time of the last failed update of the product.
+    pub last_failed_update: Option<DateTime<Utc>>,
+}
+
+impl ProductStatus {
+    /// Returns `true` if the last update of the product failed.
+    pub fn is_failing(&self) -> bool {
+        match (self.last_failed_update, self.last_successful_update) {
+            (Some(failed), Some(succeeded)) => failed > succeeded,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+}
+
+/// The last webhook sent for an `Item`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LastWebhook {
+    /// The time the webhook was sent.
+    pub sent_at: Option<DateTime<Utc>>,
+
+    /// The `webhook_code` of the webhook, e.g. `"DEFAULT_UPDATE"`.
+    pub code_sent: Option<String>,
+}
+
+/// A summary of the health of an `Item`, as returned by `item_health`.
+#[derive(Clone, Debug)]
+pub struct ItemHealth {
+    /// The ID of the Item.
+    pub item_id: String,
+
+    /// The ID of the institution of the Item, if any.
+    pub institution_id: Option<String>,
+
+    /// The number of accounts of the Item.
+    pub account_count: usize,
+
+    /// Whether the user must re-authenticate the Item, see
+    /// `Item::needs_reauth`.
+    pub needs_reauth: bool,
+
+    /// The error the Item is in, if any.
+    pub last_error: Option<crate::ApiError>,
+
+    /// The time left until the consent of the user expires, negative if it
+    /// already has, or `None` if the Item has no `consent_expiration_time`.
+    pub consent_expires_in: Option<chrono::Duration>,
+}
+
+impl ItemHealth {
+    /// Summarizes the health of the Item of an `AccountsResponse`.
+    pub fn new(response: &super::AccountsResponse) -> Self {
+        Self::at(response, Utc::now())
+    }
+
+    fn at(response: &super::AccountsResponse, now: DateTime<Utc>) -> Self {
+        let item = &response.item;
+        ItemHealth {
+            item_id: item.item_id.clone(),
+            institution_id: item.institution_id.clone(),
+            account_count: response.accounts.len(),
+            needs_reauth: item.needs_reauth(),
+            last_error: item.error.clone(),
+            consent_expires_in: item
+                .consent_expiration_time
+                .map(|expiration| expiration.with_timezone(&Utc) - now),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn summarizes_item_health() {
+        let mut value = fixtures::accounts_response();
+        value["item"]["error"] = json!({
+            "error_type": "ITEM_ERROR",
+            "error_code": "ITEM_LOGIN_REQUIRED",
+            "error_message": "the login details of this item have changed",
+            "display_message": null,
+            "request_id": "HNTDNrA8F1shFEW"
+        });
+        value["item"]["consent_expiration_time"] = json!("2024-03-16T15:53:00Z");
+        let response: crate::AccountsResponse = serde_json::from_value(value).unwrap();
+
+        let now = "2024-03-14T15:53:00Z".parse().unwrap();
+        let health = ItemHealth::at(&response, now);
+        assert_eq!(health.item_id, "DWVAAPWq4RHGlEaNyGKRTAnPLaEmo8Cvq7na6");
+        assert_eq!(health.account_count, 1);
+        assert!(health.needs_reauth);
+        assert_eq!(health.last_error.unwrap().error_code, "ITEM_LOGIN_REQUIRED");
+        assert_eq!(health.consent_expires_in, Some(chrono::Duration::days(2)));
+    }
+
+    #[test]
+    fn can_check_item_status() {
+        let response: ItemGetResponse = serde_json::from_value(json!({
+            "item": fixtures::item(),
+            "status": {
+                "transactions": {
+                    "last_successful_update": "2019-02-15T15:52:39Z",
+                    "last_failed_update": "2019-01-22T04:32:00Z"
+                },
+                "investments": {
+                    "last_successful_update": null,
+                    "last_failed_update": "2019-02-15T15:53:00Z"
+                },
+                "last_webhook": {
+                    "sent_at": "2019-02-15T15:53:00Z",
+                    "code_sent": "DEFAULT_UPDATE"
+                }
+            },
+            "request_id": "m8MDnv9okwxFNBV"
+        }))
+        .unwrap();
+
+        let status = response.status.unwrap();
+        assert_eq!(
+            status.transactions_last_update().unwrap().to_rfc3339(),
+            "2019-02-15T15:52:39+00:00"
+        );
+        assert_eq!(status.investments_last_update(), None);
+        assert!(!status.is_healthy());
+
+        let status = ItemStatus {
+            investments: None,
+            ..status
+        };
+        assert!(status.is_healthy());
+        assert!(ItemStatus::default().is_healthy());
+    }
+}
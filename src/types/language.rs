@@ -0,0 +1,14 @@
+//! Language types.
+
+use serde::{Deserialize, Serialize};
+
+/// Supported languages.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs, non_camel_case_types)]
+pub enum SupportedLanguage {
+    en,
+    fr,
+    es,
+    nl,
+}
@@ -1,22 +1,50 @@
 //! Request and response types.
 
 use std::convert::TryFrom;
+use std::error::Error as StdError;
 use std::fmt;
 use std::str::FromStr;
 
 pub use account::*;
+pub use amount::*;
+pub use assets::*;
 pub use auth::*;
+pub use country::*;
+pub use identity::*;
+pub use institution::*;
+pub use investments::*;
 pub use item::*;
+pub use language::*;
+pub use payment_initiation::*;
+pub use product::*;
 pub use secrecy::{ExposeSecret, SecretString};
 use serde::ser::Serializer;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 pub use token::*;
+pub use transactions::*;
+pub use transfer::*;
+pub use user::*;
 
 mod account;
+mod amount;
+mod assets;
 mod auth;
+mod country;
+mod identity;
+mod institution;
+mod investments;
 mod item;
+mod language;
+mod payment_initiation;
+mod product;
+#[cfg(test)]
+mod round_trip;
 pub(crate) mod serde_utils;
 mod token;
+mod transactions;
+mod transfer;
+mod user;
 pub mod webhook;
 
 /// A [secure] representation of a [Plaid API secret].
@@ -53,9 +81,45 @@ impl Serialize for Secret {
     }
 }
 
+/// The credentials used to authenticate requests to the Plaid API.
+///
+/// See: [https://dashboard.plaid.com/team/keys](https://dashboard.plaid.com/team/keys)
+#[derive(Clone, Debug)]
+pub struct Credentials {
+    /// Your Plaid API `client_id`.
+    pub client_id: String,
+
+    /// Your Plaid API `secret`.
+    pub secret: Secret,
+}
+
+impl Credentials {
+    /// Creates new `Credentials`.
+    pub fn new<C, S>(client_id: C, secret: S) -> Self
+    where
+        C: Into<String>,
+        S: Into<Secret>,
+    {
+        Credentials {
+            client_id: client_id.into(),
+            secret: secret.into(),
+        }
+    }
+
+    /// Adds the `client_id` and `secret` fields to a JSON request body.
+    ///
+    /// Bodies that are not JSON objects are left unchanged.
+    pub fn inject(&self, body: &mut serde_json::Value) {
+        if let Some(body) = body.as_object_mut() {
+            body.insert("client_id".to_string(), json!(self.client_id));
+            body.insert("secret".to_string(), json!(self.secret));
+        }
+    }
+}
+
 /// API environments to differentiate between testing environments (`Sandbox`
 /// and `Development`) and live, billed, unrestricted API access (`Production`).
-#[derive(PartialEq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum Environment {
     /// Sandbox environment.
     ///
@@ -75,9 +139,25 @@ pub enum Environment {
     Production,
 }
 
+impl Environment {
+    /// Returns `true` for the `Production` environment, where all activity is
+    /// billed.
+    #[inline]
+    pub fn is_production(&self) -> bool {
+        matches!(self, Environment::Production)
+    }
+
+    /// Returns `true` for environments that operate on live `Items` at real
+    /// financial institutions (`Development` and `Production`), as opposed to
+    /// the `Sandbox` test data.
+    #[inline]
+    pub fn is_live(&self) -> bool {
+        !matches!(self, Environment::Sandbox)
+    }
+}
+
 impl FromStr for Environment {
-    // TODO: make an `Error` type.
-    type Err = String;
+    type Err = ParseEnvironmentError;
 
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -85,14 +165,15 @@ impl FromStr for Environment {
             "production" => Ok(Environment::Production),
             "development" => Ok(Environment::Development),
             "sandbox" => Ok(Environment::Sandbox),
-            val => Err(format!("invalid Plaid Environment: `{}`", val)),
+            _ => Err(ParseEnvironmentError {
+                value: s.to_string(),
+            }),
         }
     }
 }
 
 impl TryFrom<String> for Environment {
-    // TODO: make an `Error` type.
-    type Error = String;
+    type Error = ParseEnvironmentError;
 
     #[inline]
     fn try_from(value: String) -> Result<Self, Self::Error> {
@@ -101,8 +182,7 @@ impl TryFrom<String> for Environment {
 }
 
 impl<'a> TryFrom<&'a str> for Environment {
-    // TODO: make an `Error` type.
-    type Error = String;
+    type Error = ParseEnvironmentError;
 
     #[inline]
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
@@ -110,6 +190,27 @@ impl<'a> TryFrom<&'a str> for Environment {
     }
 }
 
+/// An error returned when parsing an invalid `Environment`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseEnvironmentError {
+    value: String,
+}
+
+impl ParseEnvironmentError {
+    /// The value that could not be parsed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl StdError for ParseEnvironmentError {}
+
+impl fmt::Display for ParseEnvironmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid Plaid Environment: `{}`", self.value)
+    }
+}
+
 impl fmt::Display for Environment {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -124,6 +225,7 @@ impl fmt::Display for Environment {
 
 /// Metadata about a requested `Item`.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Item {
     /// The Plaid Item ID. The `item_id` is always unique; linking the same
     /// account at the same institution twice will result in two Items with
@@ -144,18 +246,17 @@ pub struct Item {
     /// general, 200 HTTP codes correspond to success, 40X codes are for
     /// developer- or user-related failures, and 50X codes are for Plaid-related
     /// issues. Error fields will be null if no error has occurred.
-    pub error: Option<serde_json::Value>,
+    pub error: Option<crate::ApiError>,
 
-    // TODO: make a `Product` enum
     /// A list of products available for the Item that have not yet been
     /// accessed.
-    pub available_products: Option<Vec<String>>,
+    pub available_products: Option<Vec<Product>>,
 
     /// A list of products that have been billed for the Item.
     ///
     /// *Note*: billed_products is populated in all environments but only
     /// requests in Production are billed.
-    pub billed_products: Option<Vec<String>>,
+    pub billed_products: Option<Vec<Product>>,
 
     /// The [RFC 3339] timestamp after which the consent provided by the end
     /// user will expire. Upon consent expiration, the item will enter the
@@ -171,3 +272,68 @@ pub struct Item {
     /// [RFC 3339]: https://tools.ietf.org/html/rfc3339
     pub consent_expiration_time: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
+
+impl Item {
+    /// Returns `true` if the user must re-authenticate the Item through Link's
+    /// update mode, i.e. if its error is `ITEM_LOGIN_REQUIRED` or
+    /// `PENDING_EXPIRATION`, or if its `consent_expiration_time` has passed.
+    pub fn needs_reauth(&self) -> bool {
+        let error = self.error.as_ref().map(|error| error.error_code.as_str());
+        if matches!(error, Some("ITEM_LOGIN_REQUIRED" | "PENDING_EXPIRATION")) {
+            return true;
+        }
+
+        match self.consent_expiration_time {
+            Some(expiration) => expiration <= chrono::Utc::now(),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures::item;
+
+    #[test]
+    fn classifies_environments() {
+        let cases = [
+            (Environment::Sandbox, false, false),
+            (Environment::Development, false, true),
+            (Environment::Production, true, true),
+        ];
+        for (environment, is_production, is_live) in cases {
+            assert_eq!(
+                environment.is_production(),
+                is_production,
+                "{}",
+                environment
+            );
+            assert_eq!(environment.is_live(), is_live, "{}", environment);
+        }
+    }
+
+    #[test]
+    fn can_check_needs_reauth() {
+        let healthy: Item = serde_json::from_value(item()).unwrap();
+        assert!(!healthy.needs_reauth());
+
+        let mut value = item();
+        value["error"] = json!({
+            "error_type": "ITEM_ERROR",
+            "error_code": "ITEM_LOGIN_REQUIRED",
+            "error_message": "the login details of this item have changed",
+            "display_message": null,
+            "request_id": "HNTDNrA8F1shFEW"
+        });
+        let errored: Item = serde_json::from_value(value).unwrap();
+        assert!(errored.needs_reauth());
+
+        let mut value = item();
+        value["consent_expiration_time"] = json!("2020-01-01T00:00:00Z");
+        let expired: Item = serde_json::from_value(value).unwrap();
+        assert!(expired.needs_reauth());
+    }
+}
@@ -0,0 +1,282 @@
+//! Payment Initiation types.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// The currency of a Payment Initiation payment.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CurrencyCode {
+    /// British pound sterling.
+    #[serde(rename = "GBP")]
+    Gbp,
+    /// Euro.
+    #[serde(rename = "EUR")]
+    Eur,
+    /// Polish złoty.
+    #[serde(rename = "PLN")]
+    Pln,
+    /// Swedish krona.
+    #[serde(rename = "SEK")]
+    Sek,
+    /// Danish krone.
+    #[serde(rename = "DKK")]
+    Dkk,
+    /// Norwegian krone.
+    #[serde(rename = "NOK")]
+    Nok,
+    /// A currency not yet known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The amount and currency of a Payment Initiation payment.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PaymentAmount {
+    /// The currency of the payment.
+    pub currency: CurrencyCode,
+
+    /// The amount of the payment. Must contain at most two digits of
+    /// precision, e.g. `1.23`, and be at least `1`.
+    #[serde(with = "super::serde_utils::decimal_number")]
+    pub value: Decimal,
+}
+
+/// The frequency at which a standing order is executed.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentScheduleInterval {
+    /// Every week.
+    Weekly,
+    /// Every month.
+    Monthly,
+    /// An interval not yet known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The schedule of a standing order, i.e. a recurring Payment Initiation
+/// payment.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PaymentSchedule {
+    /// The frequency at which the payment is executed.
+    pub interval: PaymentScheduleInterval,
+
+    /// The day of the interval on which to execute the payment: `1` to `7`
+    /// (Monday to Sunday) for weekly payments, and `1` to `28` for monthly
+    /// payments. Negative values count back from the end of the month, e.g.
+    /// `-1` for its last day, if the institution supports
+    /// `supports_standing_order_negative_execution_days`.
+    pub interval_execution_day: i32,
+
+    /// The date of the first payment, which must be the same as or after the
+    /// date the payment is created.
+    #[serde(with = "super::serde_utils::plaid_date")]
+    pub start_date: NaiveDate,
+
+    /// The date of the last payment, if any. Omitted for standing orders that
+    /// continue until cancelled.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "super::serde_utils::plaid_date::option"
+    )]
+    pub end_date: Option<NaiveDate>,
+}
+
+/// The standing order configurations supported by an `Institution`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StandingOrderMetadata {
+    /// Indicates whether the institution supports closed-ended standing
+    /// orders, i.e. with an `end_date`.
+    pub supports_standing_order_end_date: bool,
+
+    /// Indicates whether the institution supports negative integers for the
+    /// `interval_execution_day` of a standing order.
+    pub supports_standing_order_negative_execution_days: bool,
+
+    /// The intervals at which the institution can execute standing orders.
+    #[serde(default)]
+    pub valid_standing_order_intervals: Vec<PaymentScheduleInterval>,
+}
+
+impl StandingOrderMetadata {
+    /// Returns `true` if the institution can execute a standing order with the
+    /// given schedule.
+    pub fn supports(&self, schedule: &PaymentSchedule) -> bool {
+        self.valid_standing_order_intervals
+            .contains(&schedule.interval)
+            && (schedule.end_date.is_none() || self.supports_standing_order_end_date)
+            && (schedule.interval_execution_day > 0
+                || self.supports_standing_order_negative_execution_days)
+    }
+}
+
+/// The response from performing a `payment_initiation_payment_list` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PaymentListResponse {
+    /// The payments, most recently created first.
+    pub payments: Vec<Payment>,
+
+    /// The value to pass as `cursor` to fetch the next page of payments, or
+    /// `None` if there are no more payments.
+    pub next_cursor: Option<String>,
+
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
+
+/// A Payment Initiation payment.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Payment {
+    /// The ID of the payment.
+    pub payment_id: String,
+
+    /// The amount and currency of the payment.
+    pub amount: PaymentAmount,
+
+    /// The status of the payment.
+    pub status: PaymentStatus,
+
+    /// The ID of the recipient of the payment.
+    pub recipient_id: String,
+
+    /// The schedule of the payment, if it is a standing order.
+    pub schedule: Option<PaymentSchedule>,
+
+    /// The reference of the payment, as provided when it was created.
+    pub reference: String,
+
+    /// The reference of the payment as sent to the institution, if it had to
+    /// be adjusted.
+    pub adjusted_reference: Option<String>,
+
+    /// The time of the last status update of the payment.
+    pub last_status_update: DateTime<Utc>,
+
+    /// The ID of the payment consent the payment was made with, if any.
+    pub consent_id: Option<String>,
+}
+
+/// The status of a Payment Initiation `Payment`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentStatus {
+    /// The payment is awaiting the user's authorization.
+    PaymentStatusInputNeeded,
+    /// The payment was authorized and is being processed.
+    PaymentStatusInitiated,
+    /// The payment is pending at the institution.
+    PaymentStatusPending,
+    /// The payment was authorised by the institution.
+    PaymentStatusAuthorising,
+    /// The payment was executed by the institution.
+    PaymentStatusExecuted,
+    /// The payment settled into the recipient's account.
+    PaymentStatusSettled,
+    /// The payment was cancelled.
+    PaymentStatusCancelled,
+    /// The payment was blocked.
+    PaymentStatusBlocked,
+    /// The payment was rejected by the institution.
+    PaymentStatusRejected,
+    /// The payment failed.
+    PaymentStatusFailed,
+    /// A status not yet known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn can_serde_payment() {
+        let amount = PaymentAmount {
+            currency: CurrencyCode::Gbp,
+            value: Decimal::from_str("10.10").unwrap(),
+        };
+        let value = serde_json::to_value(amount).unwrap();
+        assert_eq!(value, json!({ "currency": "GBP", "value": 10.1 }));
+        assert_eq!(
+            serde_json::from_value::<PaymentAmount>(value).unwrap(),
+            amount
+        );
+
+        let schedule = PaymentSchedule {
+            interval: PaymentScheduleInterval::Monthly,
+            interval_execution_day: -1,
+            start_date: NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+            end_date: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&schedule).unwrap(),
+            json!({
+                "interval": "MONTHLY",
+                "interval_execution_day": -1,
+                "start_date": "2024-01-31"
+            })
+        );
+    }
+
+    #[test]
+    fn can_list_payments() {
+        let response: PaymentListResponse = serde_json::from_value(json!({
+            "payments": [{
+                "payment_id": "payment-id-sandbox-feca8a7a-5591-4aef-9297-f3062bb735d3",
+                "reference": "Account Funding 99744",
+                "amount": { "currency": "GBP", "value": 100 },
+                "status": "PAYMENT_STATUS_EXECUTED",
+                "last_status_update": "2019-11-06T21:10:52Z",
+                "recipient_id": "recipient-id-sandbox-9b6b4679-914b-445b-9450-efbdb80296f6",
+                "schedule": null,
+                "adjusted_reference": "Account Funding 99",
+                "consent_id": null
+            }],
+            "next_cursor": "2020-01-01T00:00:00Z",
+            "request_id": "aEAQmewMzlVa1k6"
+        }))
+        .unwrap();
+
+        let payment = &response.payments[0];
+        assert_eq!(payment.status, PaymentStatus::PaymentStatusExecuted);
+        assert_eq!(payment.amount.value, Decimal::from(100));
+        assert_eq!(
+            response.next_cursor.as_deref(),
+            Some("2020-01-01T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn checks_standing_order_support() {
+        let metadata: StandingOrderMetadata = serde_json::from_value(json!({
+            "supports_standing_order_end_date": true,
+            "supports_standing_order_negative_execution_days": false,
+            "valid_standing_order_intervals": ["WEEKLY", "MONTHLY"]
+        }))
+        .unwrap();
+
+        let schedule = PaymentSchedule {
+            interval: PaymentScheduleInterval::Weekly,
+            interval_execution_day: 1,
+            start_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2024, 6, 1),
+        };
+        assert!(metadata.supports(&schedule));
+
+        let schedule = PaymentSchedule {
+            interval: PaymentScheduleInterval::Monthly,
+            interval_execution_day: -1,
+            ..schedule
+        };
+        assert!(!metadata.supports(&schedule));
+    }
+}
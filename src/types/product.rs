@@ -0,0 +1,254 @@
+//! Product types.
+
+use std::convert::TryFrom;
+use std::error::Error as StdError;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// A Plaid product, as reported on an Item.
+///
+/// Unlike `SupportedProduct`, this includes products that cannot be requested
+/// through Link, such as `Balance`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum Product {
+    Assets,
+    Auth,
+    Balance,
+    Identity,
+    Investments,
+    Liabilities,
+    PaymentInitiation,
+    Transactions,
+    CreditDetails,
+    Income,
+    IncomeVerification,
+    DepositSwitch,
+    StandingOrders,
+    Transfer,
+    Employment,
+    RecurringTransactions,
+    /// A product not yet known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Plaid product supported by Link.
+///
+/// *Note*: `Balance` is not a valid value, the Balance product does not require
+/// explicit initalization and will automatically be initialized when any other
+/// product is initialized.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum SupportedProduct {
+    Transactions,
+    Auth,
+    Identity,
+    Assets,
+    Investments,
+    Liabilities,
+    PaymentInitiation,
+}
+
+impl From<SupportedProduct> for Product {
+    fn from(product: SupportedProduct) -> Self {
+        match product {
+            SupportedProduct::Transactions => Product::Transactions,
+            SupportedProduct::Auth => Product::Auth,
+            SupportedProduct::Identity => Product::Identity,
+            SupportedProduct::Assets => Product::Assets,
+            SupportedProduct::Investments => Product::Investments,
+            SupportedProduct::Liabilities => Product::Liabilities,
+            SupportedProduct::PaymentInitiation => Product::PaymentInitiation,
+        }
+    }
+}
+
+impl TryFrom<Product> for SupportedProduct {
+    /// The product, returned as is when it cannot be requested through Link.
+    type Error = Product;
+
+    fn try_from(product: Product) -> Result<Self, Self::Error> {
+        match product {
+            Product::Transactions => Ok(SupportedProduct::Transactions),
+            Product::Auth => Ok(SupportedProduct::Auth),
+            Product::Identity => Ok(SupportedProduct::Identity),
+            Product::Assets => Ok(SupportedProduct::Assets),
+            Product::Investments => Ok(SupportedProduct::Investments),
+            Product::Liabilities => Ok(SupportedProduct::Liabilities),
+            Product::PaymentInitiation => Ok(SupportedProduct::PaymentInitiation),
+            other => Err(other),
+        }
+    }
+}
+
+impl Product {
+    /// The name of the product in the Plaid API, e.g. `"payment_initiation"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Product::Assets => "assets",
+            Product::Auth => "auth",
+            Product::Balance => "balance",
+            Product::Identity => "identity",
+            Product::Investments => "investments",
+            Product::Liabilities => "liabilities",
+            Product::PaymentInitiation => "payment_initiation",
+            Product::Transactions => "transactions",
+            Product::CreditDetails => "credit_details",
+            Product::Income => "income",
+            Product::IncomeVerification => "income_verification",
+            Product::DepositSwitch => "deposit_switch",
+            Product::StandingOrders => "standing_orders",
+            Product::Transfer => "transfer",
+            Product::Employment => "employment",
+            Product::RecurringTransactions => "recurring_transactions",
+            Product::Unknown => "unknown",
+        }
+    }
+}
+
+impl FromStr for Product {
+    type Err = ParseProductError;
+
+    /// Parses a product name case-insensitively, with or without underscores,
+    /// e.g. `"payment_initiation"` or `"PaymentInitiation"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.replace('_', "").to_ascii_lowercase();
+        let product = match normalized.as_str() {
+            "assets" => Product::Assets,
+            "auth" => Product::Auth,
+            "balance" => Product::Balance,
+            "identity" => Product::Identity,
+            "investments" => Product::Investments,
+            "liabilities" => Product::Liabilities,
+            "paymentinitiation" => Product::PaymentInitiation,
+            "transactions" => Product::Transactions,
+            "creditdetails" => Product::CreditDetails,
+            "income" => Product::Income,
+            "incomeverification" => Product::IncomeVerification,
+            "depositswitch" => Product::DepositSwitch,
+            "standingorders" => Product::StandingOrders,
+            "transfer" => Product::Transfer,
+            "employment" => Product::Employment,
+            "recurringtransactions" => Product::RecurringTransactions,
+            _ => {
+                return Err(ParseProductError {
+                    value: s.to_string(),
+                })
+            }
+        };
+        Ok(product)
+    }
+}
+
+impl fmt::Display for Product {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SupportedProduct {
+    type Err = ParseProductError;
+
+    /// Parses a product name like `Product`, rejecting products that cannot
+    /// be requested through Link.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let product: Product = s.parse()?;
+        SupportedProduct::try_from(product).map_err(|_| ParseProductError {
+            value: s.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for SupportedProduct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Product::from(*self).as_str())
+    }
+}
+
+/// An error returned when parsing an invalid `Product` or `SupportedProduct`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseProductError {
+    value: String,
+}
+
+impl ParseProductError {
+    /// The value that could not be parsed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl StdError for ParseProductError {}
+
+impl fmt::Display for ParseProductError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid Plaid product: `{}`", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn can_deserialize_products() {
+        let products: Vec<Product> =
+            serde_json::from_value(json!(["balance", "payment_initiation", "signal"])).unwrap();
+        assert_eq!(
+            products,
+            vec![
+                Product::Balance,
+                Product::PaymentInitiation,
+                Product::Unknown
+            ]
+        );
+    }
+
+    #[test]
+    fn can_parse_products() {
+        assert_eq!(
+            "payment_initiation".parse::<Product>(),
+            Ok(Product::PaymentInitiation)
+        );
+        assert_eq!(
+            "PaymentInitiation".parse::<Product>(),
+            Ok(Product::PaymentInitiation)
+        );
+        assert_eq!(Product::PaymentInitiation.to_string(), "payment_initiation");
+        assert_eq!(
+            serde_json::to_value(Product::IncomeVerification).unwrap(),
+            json!(Product::IncomeVerification.to_string())
+        );
+
+        assert!(matches!(
+            "AUTH".parse::<SupportedProduct>(),
+            Ok(SupportedProduct::Auth)
+        ));
+        assert_eq!(SupportedProduct::Liabilities.to_string(), "liabilities");
+
+        let err = "balance".parse::<SupportedProduct>().unwrap_err();
+        assert_eq!(err.value(), "balance");
+        assert_eq!(err.to_string(), "invalid Plaid product: `balance`");
+        assert!("unknown".parse::<Product>().is_err());
+    }
+
+    #[test]
+    fn can_convert_between_products() {
+        assert_eq!(Product::from(SupportedProduct::Auth), Product::Auth);
+        assert!(matches!(
+            SupportedProduct::try_from(Product::Transactions),
+            Ok(SupportedProduct::Transactions)
+        ));
+        assert_eq!(
+            SupportedProduct::try_from(Product::Balance).unwrap_err(),
+            Product::Balance
+        );
+    }
+}
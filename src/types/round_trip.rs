@@ -0,0 +1,266 @@
+//! Round-trip tests of the response types against canonical Plaid fixtures.
+//!
+//! Each fixture is deserialized and serialized again, and must come back
+//! unchanged up to the order of fields, fields that are `null` or empty lists,
+//! and the representation of numbers. This catches regressions in the custom
+//! serde attributes of the types and documents the shapes Plaid sends.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use super::*;
+use crate::{fixtures, Webhook};
+
+/// Drops `null` fields and empty lists from objects, which Plaid treats like
+/// omitted fields, and turns every number into a float.
+fn normalize(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, value)| match value {
+                    Value::Null => false,
+                    Value::Array(values) => !values.is_empty(),
+                    _ => true,
+                })
+                .map(|(key, value)| (key, normalize(value)))
+                .collect::<Map<_, _>>(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(normalize).collect()),
+        Value::Number(number) => json!(number.as_f64()),
+        value => value,
+    }
+}
+
+#[track_caller]
+fn assert_round_trip<T>(fixture: Value)
+where
+    T: Serialize + DeserializeOwned,
+{
+    let parsed: T = serde_json::from_value(fixture.clone()).unwrap();
+    let value = serde_json::to_value(&parsed).unwrap();
+    assert_eq!(normalize(value), normalize(fixture));
+}
+
+fn item() -> Value {
+    let mut item = fixtures::item();
+    item["consent_expiration_time"] = json!("2024-03-16T15:53:00Z");
+    item
+}
+
+fn account() -> Value {
+    let mut account = fixtures::account();
+    account["balances"]["current"] = json!(110.5);
+    account["verification_status"] = json!("pending_automatic_verification");
+    account["days_available"] = json!(null);
+    account
+}
+
+fn transaction() -> Value {
+    let mut transaction = fixtures::transaction();
+    transaction["personal_finance_category"] = json!({
+        "primary": "GENERAL_MERCHANDISE",
+        "detailed": "GENERAL_MERCHANDISE_ELECTRONICS",
+        "confidence_level": "VERY_HIGH"
+    });
+    transaction["personal_finance_category_icon_url"] =
+        json!("https://plaid-category-icons.plaid.com/PFC_GENERAL_MERCHANDISE.png");
+    transaction["merchant_entity_id"] = json!("O5W5j4dN9OR3E6ypQmjdkWZZRoXEzVMz2ByWM");
+    transaction["logo_url"] = json!("https://plaid-merchant-logos.plaid.com/apple_12.png");
+    transaction["website"] = json!("apple.com");
+    transaction["payment_channel"] = json!("in store");
+    transaction["transaction_type"] = json!("place");
+    transaction
+}
+
+fn institution() -> Value {
+    let mut institution = fixtures::institution();
+    institution["logo"] = json!("aWNvbg==");
+    institution
+}
+
+#[test]
+fn round_trips_accounts_response() {
+    assert_round_trip::<AccountsResponse>(json!({
+        "accounts": [account()],
+        "item": item(),
+        "request_id": "bkVE1BHWMAZ9Rnr"
+    }));
+}
+
+#[test]
+fn round_trips_auth_response() {
+    assert_round_trip::<AuthResponse>(json!({
+        "accounts": [account()],
+        "numbers": {
+            "ach": [{
+                "account_id": "blgvvBlXw3cq5GMPwqB6s6q4dLKB9WcVqGDGo",
+                "account": "1111222233330000",
+                "routing": "011401533",
+                "wire_routing": "021000021"
+            }],
+            "eft": [{
+                "account_id": "blgvvBlXw3cq5GMPwqB6s6q4dLKB9WcVqGDGo",
+                "account": "111122223333",
+                "institution": "021",
+                "branch": "01140"
+            }],
+            "international": [{
+                "account_id": "blgvvBlXw3cq5GMPwqB6s6q4dLKB9WcVqGDGo",
+                "iban": "GB33BUKB20201555555555",
+                "bic": "GB33BUKB"
+            }],
+            "bacs": [{
+                "account_id": "blgvvBlXw3cq5GMPwqB6s6q4dLKB9WcVqGDGo",
+                "account": "31926819",
+                "sort_code": "601613"
+            }]
+        },
+        "item": item(),
+        "request_id": "m8MDnv9okwxFNBV"
+    }));
+}
+
+#[test]
+fn round_trips_institution_response() {
+    assert_round_trip::<InstitutionResponse>(json!({
+        "institution": institution(),
+        "request_id": "m8MDnv9okwxFNBV"
+    }));
+    assert_round_trip::<InstitutionsGetResponse>(json!({
+        "institutions": [institution()],
+        "total": 11384,
+        "request_id": "Ggmk0enW4smO2Tp"
+    }));
+}
+
+#[test]
+fn round_trips_item_get_response() {
+    assert_round_trip::<ItemGetResponse>(json!({
+        "item": item(),
+        "status": {
+            "investments": {
+                "last_successful_update": "2019-02-15T15:52:39Z",
+                "last_failed_update": "2019-01-22T04:32:00Z"
+            },
+            "transactions": {
+                "last_successful_update": "2019-02-15T15:52:39Z",
+                "last_failed_update": "2019-01-22T04:32:00Z"
+            },
+            "last_webhook": {
+                "sent_at": "2019-02-15T15:53:00Z",
+                "code_sent": "DEFAULT_UPDATE"
+            }
+        },
+        "request_id": "m8MDnv9okwxFNBV"
+    }));
+}
+
+#[test]
+fn round_trips_link_token_responses() {
+    assert_round_trip::<CreateLinkTokenResponse>(json!({
+        "link_token": "link-sandbox-af1a0311-da53-4636-b754-dd15cc058176",
+        "expiration": "2020-03-27T12:56:34Z",
+        "request_id": "XQVgFigpGHXkb0b"
+    }));
+    assert_round_trip::<ExchangePublicTokenResponse>(json!({
+        "access_token": "access-sandbox-de3ce8ef-33f8-452c-a685-8671031fc0f6",
+        "item_id": "M5eVJqLnv3tbzdngLDp9FL5OlDNxlNhlE55op",
+        "request_id": "Aim3b"
+    }));
+}
+
+#[test]
+fn round_trips_payment_list_response() {
+    assert_round_trip::<PaymentListResponse>(json!({
+        "payments": [{
+            "payment_id": "payment-id-sandbox-feca8a7a-5591-4aef-9297-f3062bb735d3",
+            "reference": "Account Funding 99744",
+            "amount": { "currency": "GBP", "value": 100.5 },
+            "status": "PAYMENT_STATUS_EXECUTED",
+            "last_status_update": "2019-11-06T21:10:52Z",
+            "recipient_id": "recipient-id-sandbox-9b6b4679-914b-445b-9450-efbdb80296f6",
+            "schedule": {
+                "interval": "MONTHLY",
+                "interval_execution_day": 1,
+                "start_date": "2019-12-01",
+                "end_date": "2020-12-01"
+            },
+            "adjusted_reference": "Account Funding 99",
+            "consent_id": null
+        }],
+        "next_cursor": "2020-01-01T00:00:00Z",
+        "request_id": "aEAQmewMzlVa1k6"
+    }));
+}
+
+#[test]
+fn round_trips_transactions_responses() {
+    assert_round_trip::<TransactionsGetResponse>(json!({
+        "accounts": [account()],
+        "transactions": [transaction()],
+        "total_transactions": 1,
+        "item": item(),
+        "request_id": "45QSn"
+    }));
+    assert_round_trip::<TransactionsSyncResponse>(json!({
+        "added": [transaction()],
+        "modified": [transaction()],
+        "removed": [{ "transaction_id": "CmdQTNgems8BT1B7ibkoUXVPyAeehT3Tmzk0l" }],
+        "next_cursor": "tVUUL15lYQN5rBnfDIc1I8xudpGdIlw9nsgeXWvhOfkECvUeR663i3Dt1uf/94S8ASkitgLcIiOSqNwzzp+bh89kirazha5vuZHBb2ZA5NtCDkkV",
+        "has_more": false,
+        "request_id": "Wvhy9PZHQLV8njG"
+    }));
+}
+
+#[test]
+fn round_trips_transfer_list_response() {
+    assert_round_trip::<TransferListResponse>(json!({
+        "transfers": [{
+            "id": "460cbe92-2dcc-8eae-5ad6-b37d0ec90fd9",
+            "account_id": "3gE5gnRzNmfJMQjzTy1nMUJGJMPyBnHGqbVDb",
+            "type": "credit",
+            "network": "ach",
+            "amount": "12.34",
+            "iso_currency_code": "USD",
+            "description": "Desc",
+            "created": "2019-12-09T17:27:15Z",
+            "status": "pending",
+            "origination_account_id": "8945fedc-e703-463d-86b1-dc0607b55460",
+            "user": {
+                "legal_name": "Anne Charleston",
+                "phone_number": "510-555-0128",
+                "email_address": "acharleston@email.com"
+            },
+            "failure_reason": {
+                "ach_return_code": "R13",
+                "description": "Invalid ACH routing number"
+            }
+        }],
+        "request_id": "saKrIBuEB9qJZno"
+    }));
+}
+
+#[test]
+fn round_trips_webhooks() {
+    assert_round_trip::<Webhook>(json!({
+        "webhook_type": "ITEM",
+        "webhook_code": "PENDING_EXPIRATION",
+        "item_id": "wz666MBjYWTp2PDzzggYhM6oWWmBb",
+        "consent_expiration_time": "2020-01-15T13:25:17.766Z",
+        "error": null
+    }));
+    assert_round_trip::<Webhook>(json!({
+        "webhook_type": "ASSETS",
+        "webhook_code": "PRODUCT_READY",
+        "asset_report_id": "47dfc92b-bba3-4583-809e-ce871b321f05",
+        "report_type": "FULL",
+        "error": null
+    }));
+    assert_round_trip::<Webhook>(json!({
+        "webhook_type": "TRANSFER",
+        "webhook_code": "TRANSFER_EVENTS_UPDATE",
+        "environment": "production",
+        "error": null
+    }));
+}
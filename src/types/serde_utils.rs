@@ -60,6 +60,111 @@ pub(crate) mod default_on_null {
     }
 }
 
+/// Ser/de for `NaiveDate` fields as `YYYY-MM-DD`.
+///
+/// Deserialization also accepts a full RFC 3339 datetime, keeping only its
+/// date in the offset it was given in.
+pub(crate) mod plaid_date {
+    use chrono::NaiveDate;
+
+    const FORMAT: &str = "%Y-%m-%d";
+
+    pub fn serialize<S>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(&date.format(FORMAT))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        use serde::Deserialize;
+        let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        parse(&value).map_err(serde::de::Error::custom)
+    }
+
+    fn parse(value: &str) -> Result<NaiveDate, chrono::ParseError> {
+        NaiveDate::parse_from_str(value, FORMAT).or_else(|error| {
+            chrono::DateTime::parse_from_rfc3339(value)
+                .map(|datetime| datetime.date_naive())
+                .map_err(|_| error)
+        })
+    }
+
+    /// Ser/de for `Option<NaiveDate>` fields, see [`plaid_date`](super).
+    pub(crate) mod option {
+        use chrono::NaiveDate;
+
+        pub fn serialize<S>(date: &Option<NaiveDate>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            match date {
+                Some(date) => serializer.collect_str(&date.format(super::FORMAT)),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+        where
+            D: serde::de::Deserializer<'de>,
+        {
+            use serde::Deserialize;
+            Option::<std::borrow::Cow<'de, str>>::deserialize(deserializer)?
+                .map(|value| super::parse(&value).map_err(serde::de::Error::custom))
+                .transpose()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use chrono::NaiveDate;
+        use serde_json::json;
+
+        #[derive(serde::Serialize, serde::Deserialize, Debug)]
+        struct TestValue {
+            #[serde(with = "super")]
+            date: NaiveDate,
+            #[serde(default, with = "super::option")]
+            optional_date: Option<NaiveDate>,
+        }
+
+        #[test]
+        fn can_serde_date() {
+            let value: TestValue =
+                serde_json::from_value(json!({ "date": "2020-05-28", "optional_date": null }))
+                    .unwrap();
+            assert_eq!(value.date, NaiveDate::from_ymd_opt(2020, 5, 28).unwrap());
+            assert_eq!(value.optional_date, None);
+            assert_eq!(
+                serde_json::to_value(&value).unwrap(),
+                json!({ "date": "2020-05-28", "optional_date": null })
+            );
+        }
+
+        #[test]
+        fn truncates_datetime_to_date() {
+            let value: TestValue = serde_json::from_value(json!({
+                "date": "2020-05-28T23:30:00-05:00",
+                "optional_date": "2020-05-29T00:00:00Z",
+            }))
+            .unwrap();
+            assert_eq!(value.date, NaiveDate::from_ymd_opt(2020, 5, 28).unwrap());
+            assert_eq!(
+                value.optional_date,
+                Some(NaiveDate::from_ymd_opt(2020, 5, 29).unwrap())
+            );
+        }
+
+        #[test]
+        fn rejects_invalid_date() {
+            assert!(serde_json::from_value::<TestValue>(json!({ "date": "05/28/2020" })).is_err());
+        }
+    }
+}
+
 // TODO: is there a crate or something that will support this?
 // HACK: https://github.com/serde-rs/serde/issues/1560
 macro_rules! named_unit_variant {
@@ -101,6 +206,133 @@ macro_rules! named_unit_variant {
     };
 }
 
+/// Ser/de for `Decimal` fields as JSON numbers.
+///
+/// The shortest representation of the `f64` is parsed, so that e.g. `10.1` is
+/// deserialized as exactly `10.1`.
+pub(crate) mod decimal_number {
+    use std::str::FromStr;
+
+    use rust_decimal::prelude::ToPrimitive;
+    use rust_decimal::Decimal;
+
+    pub fn serialize<S>(value: &Decimal, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let value = value
+            .to_f64()
+            .ok_or_else(|| serde::ser::Error::custom("decimal out of range"))?;
+        serializer.serialize_f64(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        use serde::Deserialize;
+        let value = f64::deserialize(deserializer)?;
+        Decimal::from_str(&value.to_string()).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Deserialization of numeric fields that Plaid sends either as JSON numbers
+/// or as strings, e.g. `12.5` or `"12.5"`.
+pub(crate) mod num_or_str {
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    use serde::{Deserialize, Deserializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumOrStr<T> {
+        Num(T),
+        Str(String),
+    }
+
+    impl<T> NumOrStr<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        fn parse<E>(self) -> Result<T, E>
+        where
+            E: serde::de::Error,
+        {
+            match self {
+                NumOrStr::Num(value) => Ok(value),
+                NumOrStr::Str(value) => value.trim().parse().map_err(E::custom),
+            }
+        }
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de> + FromStr,
+        T::Err: Display,
+    {
+        NumOrStr::deserialize(deserializer)?.parse()
+    }
+
+    /// Deserialization of `Option` fields, see [`num_or_str`](super).
+    pub(crate) mod option {
+        use std::fmt::Display;
+        use std::str::FromStr;
+
+        use serde::{Deserialize, Deserializer};
+
+        pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Deserialize<'de> + FromStr,
+            T::Err: Display,
+        {
+            Option::<super::NumOrStr<T>>::deserialize(deserializer)?
+                .map(super::NumOrStr::parse)
+                .transpose()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use serde_json::json;
+
+        #[derive(serde::Deserialize, Debug)]
+        struct TestValue {
+            #[serde(deserialize_with = "super::deserialize")]
+            amount: f64,
+            #[serde(default, deserialize_with = "super::option::deserialize")]
+            limit: Option<f64>,
+        }
+
+        #[test]
+        fn accepts_numbers_and_strings() {
+            let value: TestValue =
+                serde_json::from_value(json!({ "amount": 12.5, "limit": 100 })).unwrap();
+            assert_eq!(value.amount, 12.5);
+            assert_eq!(value.limit, Some(100.0));
+
+            let value: TestValue =
+                serde_json::from_value(json!({ "amount": "12.5", "limit": "100" })).unwrap();
+            assert_eq!(value.amount, 12.5);
+            assert_eq!(value.limit, Some(100.0));
+
+            let value: TestValue =
+                serde_json::from_value(json!({ "amount": -3, "limit": null })).unwrap();
+            assert_eq!(value.amount, -3.0);
+            assert_eq!(value.limit, None);
+
+            let value: TestValue = serde_json::from_value(json!({ "amount": 1 })).unwrap();
+            assert_eq!(value.limit, None);
+
+            let result = serde_json::from_value::<TestValue>(json!({ "amount": "twelve" }));
+            assert!(result.is_err());
+        }
+    }
+}
+
 pub(crate) mod strings {
     named_unit_variant!(home);
     named_unit_variant!(work);
@@ -1,8 +1,12 @@
+use std::error::Error as StdError;
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
+use super::{Environment, SupportedCountry, SupportedLanguage, SupportedProduct};
+
 // TODO: make a `link` module?
 
-// TODO: clean API to encode invariants of `CreateLinkTokenRequest`
 /// The body for the `create_link_token` request.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CreateLinkTokenRequest {
@@ -130,10 +134,343 @@ pub struct CreateLinkTokenRequest {
     /// *Note*: This field is required if `payment_initiation` is included in
     /// the product array.
     pub payment_initiation: Option<PaymentInitiationConfiguration>,
+
+    /// Configuration parameters for Hosted Link.
+    ///
+    /// When set, the response will include a `hosted_link_url` that the end
+    /// user can be sent to in order to complete the Link flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hosted_link: Option<HostedLinkConfiguration>,
+
+    /// The `user_token` of the user, as returned by `user_create`.
+    ///
+    /// Required for the Consumer Report products.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_token: Option<String>,
+
+    /// Configuration parameters for the Transactions product.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transactions: Option<TransactionsLinkConfig>,
+
+    /// Whether the billing of `products` has been acknowledged, which
+    /// silences `billing_advisory` and lets `create_link_token` send the
+    /// request in Production. Not sent to Plaid.
+    #[serde(skip)]
+    pub billing_acknowledged: bool,
+}
+
+impl CreateLinkTokenRequest {
+    /// Creates a builder for a `CreateLinkTokenRequest` with the fields that
+    /// are required for every request.
+    pub fn builder<N>(
+        client_name: N,
+        language: SupportedLanguage,
+        country_codes: Vec<SupportedCountry>,
+        user: EndUser,
+    ) -> CreateLinkTokenRequestBuilder
+    where
+        N: Into<String>,
+    {
+        CreateLinkTokenRequestBuilder {
+            #[allow(deprecated)]
+            request: CreateLinkTokenRequest {
+                client_name: client_name.into(),
+                language,
+                country_codes,
+                user,
+                products: vec![],
+                webhook: None,
+                access_token: None,
+                link_customization_name: None,
+                redirect_uri: None,
+                android_package_name: None,
+                account_filters: None,
+                institution_id: None,
+                payment_initiation: None,
+                hosted_link: None,
+                user_token: None,
+                transactions: None,
+                billing_acknowledged: false,
+            },
+        }
+    }
+
+    /// Creates a request for a `link_token` that launches Link in update mode
+    /// for the Item of `access_token`, e.g. to fix an `ITEM_LOGIN_REQUIRED`
+    /// error. No `products` are set, as update mode does not accept them.
+    pub fn update_mode<A, N>(
+        access_token: A,
+        client_name: N,
+        language: SupportedLanguage,
+        country_codes: Vec<SupportedCountry>,
+        user: EndUser,
+    ) -> Self
+    where
+        A: Into<String>,
+        N: Into<String>,
+    {
+        Self::builder(client_name, language, country_codes, user)
+            .access_token(access_token)
+            .request
+    }
+
+    /// The number of `products` above which `billing_advisory` warns about
+    /// Production link tokens.
+    pub const BILLING_ADVISORY_THRESHOLD: usize = 2;
+
+    /// Warns about creating the `link_token` in the given `environment` if it
+    /// is `Production` and more than `BILLING_ADVISORY_THRESHOLD` products are
+    /// requested, unless `billing_acknowledged` is set.
+    ///
+    /// Every product is billed once the Item is initialized with it, and can
+    /// only be removed by removing the Item, so requesting products that are
+    /// not needed yet is a costly mistake. `create_link_token` refuses such
+    /// requests with `Error::BillingNotAcknowledged`.
+    pub fn billing_advisory(&self, environment: Environment) -> Option<BillingAdvisory> {
+        if environment != Environment::Production
+            || self.billing_acknowledged
+            || self.products.len() <= Self::BILLING_ADVISORY_THRESHOLD
+        {
+            return None;
+        }
+
+        Some(BillingAdvisory {
+            products: self.products.clone(),
+        })
+    }
+
+    /// Checks the invariants between fields that Plaid would otherwise reject
+    /// (or silently misbehave on) when creating the `link_token`.
+    pub fn validate(&self) -> Result<(), CreateLinkTokenRequestError> {
+        if self.redirect_uri.is_some() && self.android_package_name.is_some() {
+            return Err(CreateLinkTokenRequestError::RedirectUriWithAndroidPackageName);
+        }
+
+        if self.products.is_empty() && self.access_token.is_none() {
+            return Err(CreateLinkTokenRequestError::MissingProducts);
+        }
+
+        if let Some(transactions) = &self.transactions {
+            let range = TransactionsLinkConfig::MIN_DAYS_REQUESTED
+                ..=TransactionsLinkConfig::MAX_DAYS_REQUESTED;
+            if !range.contains(&transactions.days_requested) {
+                return Err(CreateLinkTokenRequestError::InvalidDaysRequested);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The platform Link will be launched on.
+///
+/// Determines which of `redirect_uri` and `android_package_name` is set on a
+/// `CreateLinkTokenRequest`, as they are mutually exclusive.
+#[derive(Clone, Debug)]
+pub enum Platform {
+    /// Link launched in the browser or via a webview.
+    Web {
+        /// The URI the user should be forwarded to after completing an OAuth
+        /// flow. See `CreateLinkTokenRequest::redirect_uri`.
+        redirect_uri: String,
+    },
+
+    /// Link launched on Android.
+    Android {
+        /// The name of the app's Android package. See
+        /// `CreateLinkTokenRequest::android_package_name`.
+        package_name: String,
+    },
+
+    /// Link launched on iOS, which uses the client-side `oauthRedirectUri`
+    /// parameter instead.
+    Ios,
+}
+
+/// A builder for a `CreateLinkTokenRequest`.
+///
+/// Created with `CreateLinkTokenRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct CreateLinkTokenRequestBuilder {
+    request: CreateLinkTokenRequest,
+}
+
+impl CreateLinkTokenRequestBuilder {
+    /// Sets the Plaid product(s) to use. See `CreateLinkTokenRequest::products`.
+    pub fn products(mut self, products: Vec<SupportedProduct>) -> Self {
+        self.request.products = products;
+        self
+    }
+
+    /// Sets the destination URL to which any webhooks should be sent.
+    pub fn webhook<S>(mut self, webhook: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.request.webhook = Some(webhook.into());
+        self
+    }
+
+    /// Sets the `access_token` of the Item to update, launching Link in update
+    /// mode.
+    pub fn access_token<S>(mut self, access_token: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.request.access_token = Some(access_token.into());
+        self
+    }
+
+    /// Sets the name of the Link customization to apply.
+    pub fn link_customization_name<S>(mut self, name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.request.link_customization_name = Some(name.into());
+        self
+    }
+
+    /// Sets the platform Link will be launched on.
+    pub fn platform(mut self, platform: Platform) -> Self {
+        let (redirect_uri, android_package_name) = match platform {
+            Platform::Web { redirect_uri } => (Some(redirect_uri), None),
+            Platform::Android { package_name } => (None, Some(package_name)),
+            Platform::Ios => (None, None),
+        };
+        self.request.redirect_uri = redirect_uri;
+        self.request.android_package_name = android_package_name;
+        self
+    }
+
+    /// Sets the account subtypes to be shown in Link. See
+    /// `CreateLinkTokenRequest::account_filters`.
+    pub fn account_filters(
+        mut self,
+        account_filters: serde_json::Map<String, serde_json::Value>,
+    ) -> Self {
+        self.request.account_filters = Some(account_filters);
+        self
+    }
+
+    /// Sets the options for the Payment Initiation (UK) product.
+    pub fn payment_initiation(
+        mut self,
+        payment_initiation: PaymentInitiationConfiguration,
+    ) -> Self {
+        self.request.payment_initiation = Some(payment_initiation);
+        self
+    }
+
+    /// Sets the configuration parameters for Hosted Link.
+    pub fn hosted_link(mut self, hosted_link: HostedLinkConfiguration) -> Self {
+        self.request.hosted_link = Some(hosted_link);
+        self
+    }
+
+    /// Sets the `user_token` of the user, as returned by `user_create`.
+    pub fn user_token<S>(mut self, user_token: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.request.user_token = Some(user_token.into());
+        self
+    }
+
+    /// Sets the configuration parameters for the Transactions product.
+    pub fn transactions(mut self, transactions: TransactionsLinkConfig) -> Self {
+        self.request.transactions = Some(transactions);
+        self
+    }
+
+    /// Acknowledges that every product will be billed, silencing
+    /// `CreateLinkTokenRequest::billing_advisory`.
+    pub fn acknowledge_billing(mut self) -> Self {
+        self.request.billing_acknowledged = true;
+        self
+    }
+
+    /// Builds the `CreateLinkTokenRequest`, checking it with
+    /// `CreateLinkTokenRequest::validate`.
+    pub fn build(self) -> Result<CreateLinkTokenRequest, CreateLinkTokenRequestError> {
+        self.request.validate()?;
+        Ok(self.request)
+    }
+}
+
+/// An invalid combination of fields in a `CreateLinkTokenRequest`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CreateLinkTokenRequestError {
+    /// Both `redirect_uri` and `android_package_name` were set; if
+    /// `android_package_name` is specified, `redirect_uri` must be left blank.
+    RedirectUriWithAndroidPackageName,
+
+    /// No `products` were specified outside of update mode (i.e. without an
+    /// `access_token`).
+    MissingProducts,
+
+    /// `transactions.days_requested` is outside of
+    /// `TransactionsLinkConfig::MIN_DAYS_REQUESTED` to
+    /// `TransactionsLinkConfig::MAX_DAYS_REQUESTED`.
+    InvalidDaysRequested,
+}
+
+impl StdError for CreateLinkTokenRequestError {}
+
+impl fmt::Display for CreateLinkTokenRequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CreateLinkTokenRequestError::RedirectUriWithAndroidPackageName => {
+                write!(
+                    f,
+                    "`redirect_uri` and `android_package_name` are mutually exclusive"
+                )
+            }
+            CreateLinkTokenRequestError::MissingProducts => {
+                write!(
+                    f,
+                    "`products` must be specified unless launching Link in update mode"
+                )
+            }
+            CreateLinkTokenRequestError::InvalidDaysRequested => {
+                write!(
+                    f,
+                    "`transactions.days_requested` must be between {} and {}",
+                    TransactionsLinkConfig::MIN_DAYS_REQUESTED,
+                    TransactionsLinkConfig::MAX_DAYS_REQUESTED
+                )
+            }
+        }
+    }
+}
+
+/// A warning that a Production `link_token` requests many billed products, as
+/// returned by `CreateLinkTokenRequest::billing_advisory`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BillingAdvisory {
+    /// The products that will be billed.
+    pub products: Vec<SupportedProduct>,
+}
+
+impl fmt::Display for BillingAdvisory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} products will be billed once the Item is initialized with them: ",
+            self.products.len()
+        )?;
+        for (i, product) in self.products.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", product)?;
+        }
+        Ok(())
+    }
 }
 
 /// The response from performing a `create_link_token` request.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CreateLinkTokenResponse {
     /// A `link_token`, which can be supplied to Link in order to initialize it
     /// and receive a public_token, which can be exchanged for an
@@ -149,6 +486,17 @@ pub struct CreateLinkTokenResponse {
     /// existing access_token by launching Link in update mode) expires after 30
     /// minutes.
     pub expiration: chrono::DateTime<chrono::FixedOffset>,
+
+    /// A URL of a Plaid-hosted Link flow that will use the Link token returned
+    /// by this request.
+    ///
+    /// Only present if the request was made with `hosted_link` set.
+    pub hosted_link_url: Option<String>,
+
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
 }
 
 /// The body for the `sandbox_create_public_token` request.
@@ -193,6 +541,10 @@ pub struct SandboxCreatePublicTokenRequestOptions {
     ///
     /// Default: `pass_good`  
     pub override_password: String,
+
+    /// The date range of the transactions to generate for the `Item`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transactions: Option<SandboxTransactionsConfig>,
 }
 
 impl Default for SandboxCreatePublicTokenRequestOptions {
@@ -201,12 +553,34 @@ impl Default for SandboxCreatePublicTokenRequestOptions {
             webhook: None,
             override_username: "user_good".to_string(),
             override_password: "pass_good".to_string(),
+            transactions: None,
         }
     }
 }
 
+/// The date range of the transactions generated for a `Sandbox` `Item`.
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct SandboxTransactionsConfig {
+    /// The earliest date for which to fetch transaction history.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "super::serde_utils::plaid_date::option"
+    )]
+    pub start_date: Option<chrono::NaiveDate>,
+
+    /// The most recent date for which to fetch transaction history.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "super::serde_utils::plaid_date::option"
+    )]
+    pub end_date: Option<chrono::NaiveDate>,
+}
+
 /// The response from performing a `create_public_token` request.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct SandboxCreatePublicTokenResponse {
     /// A `public_token` for the particular `Item` corresponding to the
     /// specified access_token
@@ -220,6 +594,7 @@ pub struct SandboxCreatePublicTokenResponse {
 
 /// The response from performing an `exchange_public_token` request.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ExchangePublicTokenResponse {
     /// The access token associated with the Item data is being requested for.
     pub access_token: String,
@@ -236,6 +611,7 @@ pub struct ExchangePublicTokenResponse {
 
 /// The response from performing an `create_processor_token` request.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CreateProcessorTokenResponse {
     /// The `processor_token` that can then be used by the Plaid partner to
     /// make API requests.
@@ -247,33 +623,6 @@ pub struct CreateProcessorTokenResponse {
     pub request_id: String,
 }
 
-/// Supported languages.
-#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
-#[serde(rename_all = "lowercase")]
-#[allow(missing_docs, non_camel_case_types)]
-pub enum SupportedLanguage {
-    en,
-    fr,
-    es,
-    nl,
-}
-
-/// Supported countries in [ISO 3166-1 alpha-2] format.
-///
-/// [ISO 3166-1 alpha-2]: https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2
-#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
-#[serde(rename_all = "UPPERCASE")]
-#[allow(missing_docs)]
-pub enum SupportedCountry {
-    US,
-    CA,
-    ES,
-    FR,
-    GB,
-    IE,
-    NL,
-}
-
 /// Supported payment processors.
 #[derive(Serialize, Deserialize, Clone, Copy, Debug)]
 #[serde(rename_all = "snake_case")]
@@ -310,24 +659,20 @@ pub struct EndUser {
     /// Personally identifiable information, such as an email address or phone
     /// number, should not be used in the `client_user_id`.
     pub client_user_id: String,
-}
 
-/// Plaid product supported by Link.
-///
-/// *Note*: `Balance` is not a valid value, the Balance product does not require
-/// explicit initalization and will automatically be initialized when any other
-/// product is initialized.
-#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
-#[serde(rename_all = "snake_case")]
-#[allow(missing_docs)]
-pub enum SupportedProduct {
-    Transactions,
-    Auth,
-    Identity,
-    Assets,
-    Investments,
-    Liabilities,
-    PaymentInitiation,
+    /// The user's phone number in [E.164] format.
+    ///
+    /// Required if Hosted Link is delivered via SMS.
+    ///
+    /// [E.164]: https://en.wikipedia.org/wiki/E.164
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+
+    /// The user's email address.
+    ///
+    /// Required if Hosted Link is delivered via email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
 }
 
 /// Options for initializing Link for use with the Payment Initiation
@@ -338,3 +683,211 @@ pub struct PaymentInitiationConfiguration {
     /// endpoint.
     pub payment_id: String,
 }
+
+/// Configuration parameters for the Transactions product when creating a
+/// `link_token`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransactionsLinkConfig {
+    /// The number of days of transaction history to request from the
+    /// institution when the Item is created, which affects both the depth of
+    /// the data and the time it takes to be extracted.
+    ///
+    /// Default: `90`, Minimum: `1`, Maximum: `730`.
+    pub days_requested: u16,
+}
+
+impl TransactionsLinkConfig {
+    /// The minimum value of `days_requested`.
+    pub const MIN_DAYS_REQUESTED: u16 = 1;
+
+    /// The maximum value of `days_requested`.
+    pub const MAX_DAYS_REQUESTED: u16 = 730;
+}
+
+/// Configuration parameters for Hosted Link.
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct HostedLinkConfiguration {
+    /// How Plaid should deliver the Hosted Link URL to the end user.
+    ///
+    /// If omitted, the URL will not be delivered by Plaid and must be sent to
+    /// the end user by other means.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_method: Option<HostedLinkDeliveryMethod>,
+
+    /// A URI that Hosted Link will redirect the end user to after they
+    /// complete the Link flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completion_redirect_uri: Option<String>,
+
+    /// Whether the Hosted Link session will be launched from a mobile app, in
+    /// which case the `completion_redirect_uri` should be a universal or app
+    /// link.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_mobile_app: Option<bool>,
+
+    /// How many seconds the Hosted Link URL will be valid for.
+    ///
+    /// Defaults to the lifetime of the `link_token` if omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_lifetime_seconds: Option<u32>,
+}
+
+/// How the Hosted Link URL is delivered to the end user.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum HostedLinkDeliveryMethod {
+    /// Deliver the URL via SMS to the phone number in the `user` object.
+    Sms,
+
+    /// Deliver the URL via email to the email address in the `user` object.
+    Email,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builder() -> CreateLinkTokenRequestBuilder {
+        CreateLinkTokenRequest::builder(
+            "My Client",
+            SupportedLanguage::en,
+            vec![SupportedCountry::US],
+            EndUser {
+                client_user_id: "01234567-89AB-CDEF-0123-456789ABCDEF".to_string(),
+                phone_number: None,
+                email_address: None,
+            },
+        )
+        .products(vec![SupportedProduct::Auth])
+    }
+
+    #[test]
+    fn platform_is_mutually_exclusive() {
+        let request = builder()
+            .platform(Platform::Android {
+                package_name: "com.example.app".to_string(),
+            })
+            .platform(Platform::Web {
+                redirect_uri: "https://example.com/oauth".to_string(),
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.redirect_uri.as_deref(),
+            Some("https://example.com/oauth")
+        );
+        assert_eq!(request.android_package_name, None);
+    }
+
+    #[test]
+    fn rejects_invalid_requests() {
+        let mut request = builder().build().unwrap();
+        request.redirect_uri = Some("https://example.com/oauth".to_string());
+        request.android_package_name = Some("com.example.app".to_string());
+        assert_eq!(
+            request.validate().unwrap_err(),
+            CreateLinkTokenRequestError::RedirectUriWithAndroidPackageName
+        );
+
+        let result = builder().products(vec![]).build();
+        assert_eq!(
+            result.unwrap_err(),
+            CreateLinkTokenRequestError::MissingProducts
+        );
+    }
+
+    #[test]
+    fn can_request_transaction_history() {
+        let request = builder().build().unwrap();
+        assert!(serde_json::to_value(&request)
+            .unwrap()
+            .get("transactions")
+            .is_none());
+
+        let request = builder()
+            .transactions(TransactionsLinkConfig {
+                days_requested: 730,
+            })
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&request).unwrap()["transactions"],
+            serde_json::json!({ "days_requested": 730 })
+        );
+
+        let result = builder()
+            .transactions(TransactionsLinkConfig { days_requested: 0 })
+            .build();
+        assert_eq!(
+            result.unwrap_err(),
+            CreateLinkTokenRequestError::InvalidDaysRequested
+        );
+    }
+
+    #[test]
+    fn can_create_update_mode_request() {
+        let request = CreateLinkTokenRequest::update_mode(
+            "access-sandbox-de3ce8ef-33f8-452c-a685-8671031fc0f6",
+            "My Client",
+            SupportedLanguage::en,
+            vec![SupportedCountry::US],
+            EndUser {
+                client_user_id: "01234567-89AB-CDEF-0123-456789ABCDEF".to_string(),
+                phone_number: None,
+                email_address: None,
+            },
+        );
+        assert_eq!(request.validate(), Ok(()));
+        assert!(request.products.is_empty());
+        assert_eq!(
+            request.access_token.as_deref(),
+            Some("access-sandbox-de3ce8ef-33f8-452c-a685-8671031fc0f6")
+        );
+    }
+
+    #[test]
+    fn advises_about_billed_products() {
+        let products = vec![
+            SupportedProduct::Auth,
+            SupportedProduct::Identity,
+            SupportedProduct::Transactions,
+        ];
+        let request = builder().products(products.clone()).build().unwrap();
+        assert_eq!(request.billing_advisory(Environment::Sandbox), None);
+
+        let advisory = request.billing_advisory(Environment::Production).unwrap();
+        assert_eq!(advisory.products, products);
+        assert_eq!(
+            advisory.to_string(),
+            "3 products will be billed once the Item is initialized with them: auth, identity, transactions"
+        );
+        assert!(serde_json::to_value(&request)
+            .unwrap()
+            .get("billing_acknowledged")
+            .is_none());
+
+        let request = builder()
+            .products(products)
+            .acknowledge_billing()
+            .build()
+            .unwrap();
+        assert_eq!(request.billing_advisory(Environment::Production), None);
+    }
+
+    #[test]
+    fn can_serialize_sandbox_transactions_config() {
+        let mut request = SandboxCreatePublicTokenRequest::default();
+        request.options.transactions = Some(SandboxTransactionsConfig {
+            start_date: chrono::NaiveDate::from_ymd_opt(2023, 1, 1),
+            end_date: None,
+        });
+
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            value["options"]["transactions"],
+            serde_json::json!({ "start_date": "2023-01-01" })
+        );
+        assert_eq!(value["options"]["override_username"], "user_good");
+    }
+}
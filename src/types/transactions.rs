@@ -0,0 +1,551 @@
+//! Transactions types.
+
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+use serde::{Deserialize, Serialize};
+
+// TODO: use a money crate
+// TODO: use tagged enum instead of both currency fields
+/// A transaction on an `Account`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Transaction {
+    /// The unique ID of the transaction. Like all Plaid identifiers, the
+    /// `transaction_id` is case sensitive.
+    pub transaction_id: String,
+
+    /// The ID of the account in which this transaction occurred.
+    pub account_id: String,
+
+    /// The settled value of the transaction, denominated in the account's
+    /// currency. Positive values when money moves out of the account; negative
+    /// values when money moves in.
+    #[serde(deserialize_with = "super::serde_utils::num_or_str::deserialize")]
+    pub amount: f64,
+
+    // TODO: use ISO 4217 library
+    /// The [ISO 4217] currency code of the transaction.
+    ///
+    /// Always null if `unofficial_currency_code` is non-null.
+    ///
+    /// [ISO 4217]: https://en.wikipedia.org/wiki/ISO_4217
+    pub iso_currency_code: Option<String>,
+
+    /// The unofficial currency code associated with the transaction.
+    ///
+    /// Always null if `iso_currency_code` is non-null.
+    pub unofficial_currency_code: Option<String>,
+
+    /// The legacy category of the transaction.
+    #[serde(flatten)]
+    pub category: Category,
+
+    /// The personal finance category of the transaction, which supersedes the
+    /// legacy `category`.
+    ///
+    /// Only populated by `transactions` if the request was made with
+    /// `include_personal_finance_category` set.
+    pub personal_finance_category: Option<PersonalFinanceCategory>,
+
+    /// The URL of an icon associated with the primary personal finance
+    /// category.
+    pub personal_finance_category_icon_url: Option<String>,
+
+    /// For pending transactions, the date that the transaction occurred; for
+    /// posted transactions, the date that the transaction posted.
+    #[serde(with = "super::serde_utils::plaid_date")]
+    pub date: chrono::NaiveDate,
+
+    /// The date that the transaction was authorized, if available.
+    #[serde(default, with = "super::serde_utils::plaid_date::option")]
+    pub authorized_date: Option<chrono::NaiveDate>,
+
+    /// The merchant name or transaction description.
+    pub name: String,
+
+    /// The merchant name, as enriched by Plaid from the `name` field.
+    pub merchant_name: Option<String>,
+
+    /// A unique, stable ID for the merchant, which can be used to group
+    /// transactions by merchant across institutions. Only populated for
+    /// recognized merchants.
+    #[serde(default)]
+    pub merchant_entity_id: Option<String>,
+
+    /// The URL of the merchant's logo. Only populated for recognized
+    /// merchants.
+    #[serde(default)]
+    pub logo_url: Option<String>,
+
+    /// The website of the merchant. Only populated for recognized merchants.
+    #[serde(default)]
+    pub website: Option<String>,
+
+    /// When `true`, identifies the transaction as pending or unsettled.
+    /// Pending transaction details (name, type, amount, category ID) may
+    /// change before they are settled.
+    pub pending: bool,
+
+    /// The ID of the pending transaction that this posted transaction
+    /// replaces, if any. Pending transactions are removed once posted, so
+    /// this links the two versions of the same transaction.
+    #[serde(default)]
+    pub pending_transaction_id: Option<String>,
+
+    /// The name of the account owner. This field is not typically populated
+    /// and only relevant when dealing with sub-accounts.
+    pub account_owner: Option<String>,
+
+    /// The channel used to make the payment.
+    pub payment_channel: Option<PaymentChannel>,
+
+    /// The legacy type of the transaction, superseded by
+    /// `personal_finance_category` and `payment_channel`.
+    pub transaction_type: Option<TransactionType>,
+}
+
+impl Transaction {
+    /// The most specific level of the legacy category hierarchy, if any.
+    pub fn leaf_category(&self) -> Option<&str> {
+        self.category.hierarchy.last().map(String::as_str)
+    }
+
+    /// The primary personal finance category if present, otherwise the most
+    /// specific level of the legacy category hierarchy.
+    pub fn preferred_category(&self) -> Option<&str> {
+        self.personal_finance_category
+            .as_ref()
+            .map(|pfc| pfc.primary.as_str())
+            .or_else(|| self.leaf_category())
+    }
+
+    /// Maps the `transaction_id` of each pending transaction that has been
+    /// posted to the `transaction_id` of the posted transaction, so that the
+    /// two are not counted twice.
+    pub fn resolve_pending(transactions: &[Transaction]) -> HashMap<String, String> {
+        transactions
+            .iter()
+            .filter(|transaction| !transaction.pending)
+            .filter_map(|transaction| {
+                let pending_id = transaction.pending_transaction_id.clone()?;
+                Some((pending_id, transaction.transaction_id.clone()))
+            })
+            .collect()
+    }
+}
+
+/// The legacy category of a `Transaction`.
+///
+/// See [/categories/get] for the full list of categories.
+///
+/// [/categories/get]: https://plaid.com/docs/api/products/transactions/#categoriesget
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Category {
+    /// The ID of the category, linking to the categories returned by
+    /// `/categories/get`.
+    pub category_id: Option<String>,
+
+    /// The hierarchy of the category, from least to most specific. For
+    /// example: `["Food and Drink", "Restaurants", "Fast Food"]`.
+    #[serde(
+        rename = "category",
+        default,
+        with = "super::serde_utils::default_on_null"
+    )]
+    pub hierarchy: Vec<String>,
+}
+
+/// The personal finance category of a `Transaction`.
+///
+/// See the [taxonomy CSV] for the full list of categories.
+///
+/// [taxonomy CSV]: https://plaid.com/documents/transactions-personal-finance-category-taxonomy.csv
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PersonalFinanceCategory {
+    /// A high level category that communicates the broad category of the
+    /// transaction.
+    pub primary: String,
+
+    /// A granular category conveying the transaction's intent.
+    pub detailed: String,
+
+    /// A description of how confident Plaid is in the provided
+    /// categorization, e.g. `"VERY_HIGH"`.
+    pub confidence_level: Option<String>,
+}
+
+/// The channel used to make a `Transaction`'s payment.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum PaymentChannel {
+    /// Transactions that took place online.
+    Online,
+    /// Transactions that were made at a physical location.
+    InStore,
+    /// Transactions that relate to banks, e.g. fees or deposits.
+    Other,
+    /// A payment channel not yet known to this library.
+    Unknown(String),
+}
+
+impl PaymentChannel {
+    /// The value of the payment channel in the Plaid API, e.g. `"in store"`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PaymentChannel::Online => "online",
+            PaymentChannel::InStore => "in store",
+            PaymentChannel::Other => "other",
+            PaymentChannel::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<String> for PaymentChannel {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "online" => PaymentChannel::Online,
+            "in store" => PaymentChannel::InStore,
+            "other" => PaymentChannel::Other,
+            _ => PaymentChannel::Unknown(value),
+        }
+    }
+}
+
+impl From<PaymentChannel> for String {
+    fn from(value: PaymentChannel) -> Self {
+        match value {
+            PaymentChannel::Unknown(value) => value,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+/// The legacy type of a `Transaction`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum TransactionType {
+    /// Transactions that took place online.
+    Digital,
+    /// Transactions that were made at a physical location.
+    Place,
+    /// Transactions that relate to banks, e.g. fees or deposits.
+    Special,
+    /// Transactions that do not fit into the other types.
+    Unresolved,
+    /// A transaction type not yet known to this library.
+    Other(String),
+}
+
+impl TransactionType {
+    /// The value of the transaction type in the Plaid API, e.g. `"digital"`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            TransactionType::Digital => "digital",
+            TransactionType::Place => "place",
+            TransactionType::Special => "special",
+            TransactionType::Unresolved => "unresolved",
+            TransactionType::Other(value) => value,
+        }
+    }
+}
+
+impl From<String> for TransactionType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "digital" => TransactionType::Digital,
+            "place" => TransactionType::Place,
+            "special" => TransactionType::Special,
+            "unresolved" => TransactionType::Unresolved,
+            _ => TransactionType::Other(value),
+        }
+    }
+}
+
+impl From<TransactionType> for String {
+    fn from(value: TransactionType) -> Self {
+        match value {
+            TransactionType::Other(value) => value,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+/// Options for the `transactions` request.
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct TransactionsRequestOptions {
+    /// A list of `account_ids` to retrieve for the Item.
+    ///
+    /// *Note*: An error will be returned if a provided `account_id` is not
+    /// associated with the Item.
+    #[serde(default, with = "super::serde_utils::default_on_null")]
+    pub account_ids: Vec<String>,
+
+    /// The number of transactions to fetch.
+    ///
+    /// Default: `100`, Maximum: `500`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+
+    /// The number of transactions to skip. The default value is `0`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+
+    /// Include the `personal_finance_category` of each transaction.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub include_personal_finance_category: bool,
+}
+
+/// The response from performing a `transactions` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransactionsGetResponse {
+    /// An array containing the accounts associated with the Item for which
+    /// transactions are being returned.
+    #[serde(default)]
+    pub accounts: Vec<super::Account>,
+
+    /// An array containing transactions from the account. Transactions are
+    /// returned in reverse chronological order, with the most recent at the
+    /// beginning of the array.
+    pub transactions: Vec<Transaction>,
+
+    /// The total number of transactions available within the date range
+    /// specified. If `total_transactions` is larger than the size of the
+    /// transactions array, more transactions are available and can be fetched
+    /// via manipulating the `offset` parameter.
+    pub total_transactions: u32,
+
+    /// Metadata about the Item.
+    pub item: super::Item,
+
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
+
+/// The response from performing a `transactions_sync` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransactionsSyncResponse {
+    /// Transactions that have been added to the Item since `cursor`, ordered
+    /// ascending by last modified time.
+    pub added: Vec<Transaction>,
+
+    /// Transactions that have been modified on the Item since `cursor`,
+    /// ordered ascending by last modified time.
+    pub modified: Vec<Transaction>,
+
+    /// Transactions that have been removed from the Item since `cursor`,
+    /// ordered ascending by last modified time.
+    pub removed: Vec<RemovedTransaction>,
+
+    /// Cursor used for fetching any future updates after the latest update
+    /// provided in this response. The cursor obtained after all pages have
+    /// been pulled (indicated by `has_more` being `false`) will be valid for
+    /// at least 1 year.
+    pub next_cursor: String,
+
+    /// Represents if more than requested count of transaction updates exist.
+    /// If true, the additional updates can be fetched by making an additional
+    /// request with `cursor` set to `next_cursor`.
+    pub has_more: bool,
+
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
+
+impl TransactionsSyncResponse {
+    /// Applies the updates in this response to `store`.
+    ///
+    /// Removals are applied first, then added and modified transactions are
+    /// upserted by `transaction_id`, so a transaction that is both removed and
+    /// re-added within the same page is kept.
+    pub fn apply_to<S>(&self, store: &mut S)
+    where
+        S: TransactionStore + ?Sized,
+    {
+        for removed in &self.removed {
+            store.remove_transaction(&removed.transaction_id);
+        }
+        for transaction in self.added.iter().chain(&self.modified) {
+            store.upsert_transaction(transaction.clone());
+        }
+    }
+}
+
+/// A transaction removed from an Item, as reported by `transactions_sync`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RemovedTransaction {
+    /// The ID of the removed transaction.
+    pub transaction_id: String,
+}
+
+/// A local store of transactions, keyed by `transaction_id`, that
+/// `TransactionsSyncResponse::apply_to` can update.
+pub trait TransactionStore {
+    /// Removes the transaction with the given ID, if present.
+    fn remove_transaction(&mut self, transaction_id: &str);
+
+    /// Inserts the transaction, replacing any with the same `transaction_id`.
+    fn upsert_transaction(&mut self, transaction: Transaction);
+}
+
+impl<S> TransactionStore for HashMap<String, Transaction, S>
+where
+    S: BuildHasher,
+{
+    fn remove_transaction(&mut self, transaction_id: &str) {
+        self.remove(transaction_id);
+    }
+
+    fn upsert_transaction(&mut self, transaction: Transaction) {
+        self.insert(transaction.transaction_id.clone(), transaction);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures::transaction;
+
+    #[test]
+    fn can_get_categories() {
+        let mut value = transaction();
+        let tx: Transaction = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(tx.category.category_id.as_deref(), Some("19013000"));
+        assert_eq!(tx.leaf_category(), Some("Computers and Electronics"));
+        assert_eq!(tx.preferred_category(), Some("Computers and Electronics"));
+
+        value["personal_finance_category"] = json!({
+            "primary": "GENERAL_MERCHANDISE",
+            "detailed": "GENERAL_MERCHANDISE_ELECTRONICS",
+            "confidence_level": "VERY_HIGH"
+        });
+        let tx: Transaction = serde_json::from_value(value).unwrap();
+        assert_eq!(tx.preferred_category(), Some("GENERAL_MERCHANDISE"));
+    }
+
+    #[test]
+    fn can_deserialize_merchant_enrichment() {
+        let tx: Transaction = serde_json::from_value(transaction()).unwrap();
+        assert_eq!(tx.merchant_entity_id, None);
+        assert_eq!(tx.logo_url, None);
+        assert_eq!(tx.website, None);
+
+        let mut value = transaction();
+        value["merchant_entity_id"] = json!("O5W5j4dN9OR3E6ypQmjdkWZZRoXEzVMz2ByWM");
+        value["logo_url"] = json!("https://plaid-merchant-logos.plaid.com/apple_42.png");
+        value["website"] = json!("apple.com");
+        let tx: Transaction = serde_json::from_value(value).unwrap();
+        assert_eq!(
+            tx.merchant_entity_id.as_deref(),
+            Some("O5W5j4dN9OR3E6ypQmjdkWZZRoXEzVMz2ByWM")
+        );
+        assert_eq!(
+            tx.logo_url.as_deref(),
+            Some("https://plaid-merchant-logos.plaid.com/apple_42.png")
+        );
+        assert_eq!(tx.website.as_deref(), Some("apple.com"));
+    }
+
+    #[test]
+    fn can_resolve_pending_transactions() {
+        let mut value = transaction();
+        value["transaction_id"] = json!("pending");
+        value["pending"] = json!(true);
+        let pending: Transaction = serde_json::from_value(value).unwrap();
+        assert_eq!(pending.pending_transaction_id, None);
+
+        let mut value = transaction();
+        value["transaction_id"] = json!("posted");
+        value["pending_transaction_id"] = json!("pending");
+        let posted: Transaction = serde_json::from_value(value).unwrap();
+
+        let resolved = Transaction::resolve_pending(&[pending, posted]);
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved["pending"], "posted");
+    }
+
+    #[test]
+    fn can_deserialize_null_category() {
+        let mut value = transaction();
+        value["category"] = json!(null);
+        value["category_id"] = json!(null);
+        let tx: Transaction = serde_json::from_value(value).unwrap();
+        assert_eq!(tx.leaf_category(), None);
+    }
+
+    #[test]
+    fn serializes_personal_finance_category_option_only_when_set() {
+        let options = TransactionsRequestOptions::default();
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            json!({ "account_ids": null })
+        );
+
+        let options = TransactionsRequestOptions {
+            include_personal_finance_category: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&options).unwrap()["include_personal_finance_category"],
+            json!(true)
+        );
+    }
+
+    #[test]
+    fn can_apply_sync_response() {
+        let mut modified = transaction();
+        modified["amount"] = json!(10.0);
+        let mut added = transaction();
+        added["transaction_id"] = json!("yhnUVvtcGGcCKU0bcz8PDQr5ZUxUXebUvbKC0");
+        let response: TransactionsSyncResponse = serde_json::from_value(json!({
+            "added": [added],
+            "modified": [modified],
+            "removed": [
+                { "transaction_id": "lPNjeW1nR6CDn5okmGQ6hEpMo4lLNoSrzqDje" },
+                { "transaction_id": "CmdQTNgems8BT1B7ibkoUXVPyAeehT3Tmzk0l" }
+            ],
+            "next_cursor": "tVUUL15lYQN5rBnfDIc1I8xudpGdIlw9nsgeXWvhOfkECvUeR663i3Dt1uf/94S8ASkitgLcIiOSqNwzzp+bh89kirazha5vuZHBb2ZA5NtCDkkV",
+            "has_more": false,
+            "request_id": "Wvhy9PZHQLV8njG"
+        }))
+        .unwrap();
+
+        let mut store: HashMap<String, Transaction> = HashMap::new();
+        let stale: Transaction = serde_json::from_value(transaction()).unwrap();
+        store.insert(stale.transaction_id.clone(), stale);
+
+        response.apply_to(&mut store);
+
+        assert_eq!(store.len(), 2);
+        assert_eq!(store["lPNjeW1nR6CDn5okmGQ6hEpMo4lLNoSrzqDje"].amount, 10.0);
+        assert!(store.contains_key("yhnUVvtcGGcCKU0bcz8PDQr5ZUxUXebUvbKC0"));
+    }
+
+    #[test]
+    fn can_serde_payment_channel_with_space() {
+        let mut value = transaction();
+        value["payment_channel"] = json!("in store");
+        value["transaction_type"] = json!("place");
+        let tx: Transaction = serde_json::from_value(value).unwrap();
+        assert_eq!(tx.payment_channel, Some(PaymentChannel::InStore));
+        assert_eq!(tx.transaction_type, Some(TransactionType::Place));
+
+        let value = serde_json::to_value(&tx).unwrap();
+        assert_eq!(value["payment_channel"], json!("in store"));
+        assert_eq!(value["transaction_type"], json!("place"));
+    }
+
+    #[test]
+    fn keeps_unknown_payment_channel() {
+        let channel: PaymentChannel = serde_json::from_value(json!("in_store")).unwrap();
+        assert_eq!(channel, PaymentChannel::Unknown("in_store".to_string()));
+        assert_eq!(serde_json::to_value(&channel).unwrap(), json!("in_store"));
+    }
+}
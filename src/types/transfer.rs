@@ -0,0 +1,392 @@
+//! Transfer types.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::Amount;
+
+/// The body for the `transfer_authorization_create` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TransferAuthorizationCreateRequest {
+    /// The Plaid `access_token` for the account that will be debited or
+    /// credited.
+    pub access_token: String,
+
+    /// The Plaid `account_id` corresponding to the end-user account that will
+    /// be debited or credited.
+    pub account_id: String,
+
+    /// The type of transfer.
+    #[serde(rename = "type")]
+    pub ty: TransferType,
+
+    /// The network or rails used for the transfer.
+    pub network: TransferNetwork,
+
+    /// The amount of the transfer.
+    pub amount: Amount,
+
+    /// Specifies the use case of the transfer. Required for transfers on an
+    /// ACH network, e.g. `"ppd"` or `"web"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ach_class: Option<String>,
+
+    /// The legal name and other information for the account holder.
+    pub user: TransferUser,
+
+    /// A unique key identifying the authorization, so that retrying the
+    /// request (e.g. after a timeout) with the same key returns the original
+    /// authorization rather than creating a new one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+}
+
+/// The type of a transfer.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferType {
+    /// Pulling funds from the end user's account.
+    Debit,
+    /// Sending funds to the end user's account.
+    Credit,
+}
+
+/// The network or rails used for a transfer.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum TransferNetwork {
+    /// Standard ACH.
+    Ach,
+    /// Same-day ACH.
+    SameDayAch,
+    /// Real-time payments.
+    Rtp,
+    /// Wire transfer.
+    Wire,
+    /// A network not yet known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The account holder of a transfer.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TransferUser {
+    /// The user's legal name.
+    pub legal_name: String,
+
+    /// The user's phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+
+    /// The user's email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+}
+
+/// The response from performing a `transfer_authorization_create` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransferAuthorizationCreateResponse {
+    /// Contains the authorization decision for a proposed transfer.
+    pub authorization: TransferAuthorization,
+
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
+
+/// The authorization decision for a proposed transfer.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TransferAuthorization {
+    /// Plaid's unique identifier for a transfer authorization.
+    pub id: String,
+
+    /// The datetime representing when the authorization was created, in the
+    /// format `2006-01-02T15:04:05Z`.
+    pub created: chrono::DateTime<chrono::FixedOffset>,
+
+    /// The decision regarding the proposed transfer.
+    pub decision: TransferAuthorizationDecision,
+
+    /// The rationale for Plaid's decision regarding a proposed transfer. It is
+    /// always set for `Declined` decisions, and may or may not be null for
+    /// `Approved` decisions.
+    pub decision_rationale: Option<DecisionRationale>,
+}
+
+/// The decision regarding a proposed transfer.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferAuthorizationDecision {
+    /// The proposed transfer has received the end user's consent and has been
+    /// approved for processing by Plaid.
+    Approved,
+    /// Plaid reviewed the proposed transfer and declined processing.
+    Declined,
+    /// An action is required before Plaid can assess the transfer risk.
+    UserActionRequired,
+    /// A decision not yet known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The rationale for Plaid's decision regarding a proposed transfer.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DecisionRationale {
+    /// A code representing the rationale for approving or declining the
+    /// proposed transfer.
+    pub code: DecisionRationaleCode,
+
+    /// A human-readable description of the code associated with a transfer
+    /// approval or transfer decline.
+    pub description: String,
+}
+
+/// A code representing the rationale for approving or declining a proposed
+/// transfer.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DecisionRationaleCode {
+    /// Transaction will result in an account with non-sufficient funds.
+    Nsf,
+    /// Transaction is considered to be high risk.
+    Risk,
+    /// Transaction would exceed the transfer limits.
+    TransferLimitReached,
+    /// Item created via a manual entry flow (i.e. Same Day Micro-deposit,
+    /// Instant Micro-deposit, or database-based verification), limited
+    /// information available.
+    ManuallyVerifiedItem,
+    /// Unable to collect the account information due to Item staleness. Can
+    /// be resolved by using Link and setting `user_token`, or launching Link
+    /// in update mode.
+    ItemLoginRequired,
+    /// Unable to collect the account information due to Payment Profile
+    /// staleness.
+    PaymentProfileLoginRequired,
+    /// Unable to collect the account information due to an unspecified error.
+    Error,
+    /// Item was migrated from another provider, limited information
+    /// available.
+    MigratedAccountItem,
+    /// A code not yet known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The body for the `transfer_list` request.
+///
+/// Every field is optional; the default lists the most recent transfers.
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct TransferListRequest {
+    /// The start of the range of transfer creation times to list, inclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<DateTime<Utc>>,
+
+    /// The end of the range of transfer creation times to list, inclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<DateTime<Utc>>,
+
+    /// The number of transfers to fetch.
+    ///
+    /// Default: `25`, Maximum: `25`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+
+    /// The number of transfers to skip. The default value is `0`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+
+    /// Only list transfers from this origination account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origination_account_id: Option<String>,
+
+    /// Only list transfers from this funding account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funding_account_id: Option<String>,
+}
+
+/// The response from performing a `transfer_list` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransferListResponse {
+    /// The transfers, most recently created first.
+    pub transfers: Vec<Transfer>,
+
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
+
+/// A transfer.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Transfer {
+    /// Plaid's unique identifier for the transfer.
+    pub id: String,
+
+    /// The Plaid `account_id` of the end-user account debited or credited.
+    pub account_id: Option<String>,
+
+    /// The type of the transfer.
+    #[serde(rename = "type")]
+    pub ty: TransferType,
+
+    /// The network or rails used for the transfer.
+    pub network: TransferNetwork,
+
+    /// The amount of the transfer.
+    pub amount: Amount,
+
+    /// The currency of the transfer amount, e.g. `"USD"`.
+    pub iso_currency_code: Option<String>,
+
+    /// The description of the transfer.
+    pub description: Option<String>,
+
+    /// The time the transfer was created.
+    pub created: DateTime<Utc>,
+
+    /// The status of the transfer.
+    pub status: TransferStatus,
+
+    /// The origination account of the transfer.
+    pub origination_account_id: Option<String>,
+
+    /// The legal name and other information for the account holder.
+    pub user: Option<TransferUser>,
+
+    /// The reason the transfer failed, if its status is `Failed` or
+    /// `Returned`.
+    pub failure_reason: Option<TransferFailure>,
+}
+
+/// The status of a `Transfer`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferStatus {
+    /// The transfer has been created but not yet submitted to the network.
+    Pending,
+    /// The transfer has been submitted to the network.
+    Posted,
+    /// The transfer has settled.
+    Settled,
+    /// The funds of the transfer are available.
+    FundsAvailable,
+    /// The transfer was cancelled.
+    Cancelled,
+    /// The transfer failed, e.g. because it was declined by the network.
+    Failed,
+    /// The transfer was returned.
+    Returned,
+    /// A status not yet known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The reason a `Transfer` failed or was returned.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TransferFailure {
+    /// The ACH return code, e.g. `"R01"`, if the transfer was returned.
+    pub ach_return_code: Option<String>,
+
+    /// A human-readable description of the reason for the failure.
+    pub description: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn can_deserialize_declined_authorization() {
+        let response: TransferAuthorizationCreateResponse = serde_json::from_value(json!({
+            "authorization": {
+                "id": "460cbe92-2dcc-8eae-5ad6-b37d0ec90fd9",
+                "created": "2020-08-06T17:27:15Z",
+                "decision": "declined",
+                "decision_rationale": {
+                    "code": "NSF",
+                    "description": "Transaction will result in an account with non-sufficient funds."
+                },
+                "guarantee_decision": null,
+                "proposed_transfer": {}
+            },
+            "request_id": "saKrIBuEB9qJZno"
+        }))
+        .unwrap();
+
+        let authorization = response.authorization;
+        assert_eq!(
+            authorization.decision,
+            TransferAuthorizationDecision::Declined
+        );
+        assert_eq!(
+            authorization.decision_rationale.unwrap().code,
+            DecisionRationaleCode::Nsf
+        );
+    }
+
+    #[test]
+    fn can_list_transfers() {
+        let response: TransferListResponse = serde_json::from_value(json!({
+            "transfers": [{
+                "id": "460cbe92-2dcc-8eae-5ad6-b37d0ec90fd9",
+                "account_id": "3gE5gnRzNyfXpBK5wEEKcymJ5albGVUqg77gr",
+                "type": "credit",
+                "network": "ach",
+                "amount": "12.34",
+                "iso_currency_code": "USD",
+                "description": "payment",
+                "created": "2019-12-09T17:27:15Z",
+                "status": "returned",
+                "origination_account_id": "8945fedc-e703-463d-86b1-dc0607b55460",
+                "user": { "legal_name": "Anne Charleston" },
+                "failure_reason": {
+                    "ach_return_code": "R01",
+                    "description": "Insufficient funds"
+                },
+                "cancellable": false
+            }],
+            "request_id": "saKrIBuEB9qJZno"
+        }))
+        .unwrap();
+
+        let transfer = &response.transfers[0];
+        assert_eq!(transfer.status, TransferStatus::Returned);
+        assert_eq!(transfer.amount.to_string(), "12.34");
+        assert_eq!(
+            transfer
+                .failure_reason
+                .as_ref()
+                .unwrap()
+                .ach_return_code
+                .as_deref(),
+            Some("R01")
+        );
+
+        assert_eq!(
+            serde_json::to_value(TransferListRequest {
+                count: Some(25),
+                ..Default::default()
+            })
+            .unwrap(),
+            json!({ "count": 25 })
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_rationale_codes() {
+        let code: DecisionRationaleCode = serde_json::from_value(json!("NEW_CODE")).unwrap();
+        assert_eq!(code, DecisionRationaleCode::Unknown);
+    }
+
+    #[test]
+    fn keeps_unknown_networks() {
+        let network: TransferNetwork = serde_json::from_value(json!("new-network")).unwrap();
+        assert_eq!(network, TransferNetwork::Unknown);
+    }
+}
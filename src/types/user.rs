@@ -0,0 +1,85 @@
+//! User types.
+
+use serde::{Deserialize, Serialize};
+
+/// The identity of the user of a Consumer Report, as provided to
+/// `user_create`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ConsumerReportUserIdentity {
+    /// The user's first name.
+    pub first_name: String,
+
+    /// The user's last name.
+    pub last_name: String,
+
+    /// The user's phone numbers, in E.164 format.
+    pub phone_numbers: Vec<String>,
+
+    /// The user's emails.
+    pub emails: Vec<String>,
+
+    /// The last 4 digits of the user's Social Security Number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssn_last_4: Option<String>,
+
+    /// The user's date of birth.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "super::serde_utils::plaid_date::option"
+    )]
+    pub date_of_birth: Option<chrono::NaiveDate>,
+
+    /// The user's primary address.
+    pub primary_address: super::AddressDetails,
+}
+
+/// The response from performing a `user_create` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct UserCreateResponse {
+    /// The user token associated with the User data is being requested for.
+    ///
+    /// Used instead of an `access_token` by the Consumer Report endpoints,
+    /// and when creating a `link_token` for the user.
+    pub user_token: String,
+
+    /// The Plaid `user_id` of the User associated with this webhook, warning,
+    /// or error.
+    pub user_id: String,
+
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn can_serialize_user_identity() {
+        let identity: ConsumerReportUserIdentity = serde_json::from_value(json!({
+            "first_name": "Carmen",
+            "last_name": "Berzatto",
+            "phone_numbers": ["+13125551212"],
+            "emails": ["carmen@example.com"],
+            "date_of_birth": "1987-01-31",
+            "primary_address": {
+                "city": "Chicago",
+                "region": "IL",
+                "street": "3200 W Armitage Ave",
+                "postal_code": "60657",
+                "country": "US"
+            }
+        }))
+        .unwrap();
+
+        let value = serde_json::to_value(&identity).unwrap();
+        assert_eq!(value["date_of_birth"], json!("1987-01-31"));
+        assert!(value.get("ssn_last_4").is_none());
+    }
+}
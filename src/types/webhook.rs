@@ -1,95 +1,172 @@
 //! Webhooks
 
+use serde::de::{Deserializer, Error as _};
+use serde::ser::Serializer;
 use serde::{Deserialize, Serialize};
 
 /// The response from performing an `update_webhook` request
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct WebhookUpdateResponse {
-    item: super::Item,
-    request_id: String,
+    /// Metadata about the Item, including the updated webhook URL.
+    pub item: super::Item,
+
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
 }
 
-/// A broad categorization of the error. Safe for programmatic use.
-#[derive(Serialize, Deserialize, Debug)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum WebhookErrorType {
-    /// Invalid Request Error
-    InvalidRequest,
-    /// Invalid Result Error
-    InvalidResult,
-    /// Invalid Input Error
-    InvalidInput,
-    /// Institution Error
-    InstitutionError,
-    /// Rate Limit Error
-    RateLimitExceeded,
-    /// API Error
-    ApiError,
-    /// Item Error
-    ItemError,
-    /// Asset Report Error
-    AssetReportError,
-    /// Recaptcha Error
-    RecaptchaError,
-    /// OAuth Error
-    OauthError,
-    /// Payment Error
-    PaymentError,
-    /// Bank Transfer Error
-    BankTransferError,
-    /// Income Verification Error
-    IncomeVerificationError,
+/// The response from performing a `sandbox_item_fire_webhook` request.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SandboxItemFireWebhookResponse {
+    /// Whether the webhook was fired.
+    pub webhook_fired: bool,
+
+    /// A unique identifier for the request, which can be used for
+    /// troubleshooting. This identifier, like all Plaid identifiers, is case
+    /// sensitive.
+    pub request_id: String,
+}
+
+impl WebhookUpdateResponse {
+    /// Checks whether `webhook` is the `WEBHOOK_UPDATE_ACKNOWLEDGED` webhook
+    /// fired for this update, i.e. whether it is for the same Item and the
+    /// webhook URL it was updated to.
+    ///
+    /// Returns the confirmation that the update took effect if so.
+    pub fn confirm(&self, webhook: &Webhook) -> Option<WebhookUpdateConfirmation> {
+        let content = match &webhook.webhook_type {
+            WebhookType::Item { content } => content,
+            _ => return None,
+        };
+
+        match &content.webhook_code {
+            crate::ItemWebhookCode::WebhookUpdateAcknowledged { new_webhook_url }
+                if content.item_id == self.item.item_id
+                    && Some(new_webhook_url) == self.item.webhook.as_ref() =>
+            {
+                Some(WebhookUpdateConfirmation {
+                    item_id: content.item_id.clone(),
+                    new_webhook_url: new_webhook_url.clone(),
+                })
+            }
+            _ => None,
+        }
+    }
 }
 
-/// We use standard HTTP response codes for success and failure notifications, and our errors are
-/// further classified by error_type. In general, 200 HTTP codes correspond to success, 40X codes
-/// are for developer- or user-related failures, and 50X codes are for Plaid-related issues.
-/// Error fields will be null if no error has occurred.
-#[derive(Serialize, Deserialize, Debug)]
-pub struct WebhookError {
-    /// A user-friendly representation of the error code. `null` if the error is not related to user
-    /// action. This may change over time and is not safe for programmatic use.
-    pub display_message: Option<String>,
-    /// The particular error code
-    pub error_code: String,
-    /// A developer-friendly representation of the error code. This may change over time and is not
-    /// safe for programmatic use.
-    pub error_message: String,
-    /// A broad categorization of the error
-    pub error_type: WebhookErrorType,
-    /// A unique ID identifying the request, to be used for troubleshooting purposes. This field
-    /// will be omitted in errors provided by webhooks.
-    pub request_id: Option<String>,
-    /// In the `Assets` product, a request can pertain to more than one `Item`. If an error is returned
-    /// for such a request, causes will return an array of errors containing a breakdown of these
-    /// errors on the individual `Item` level, if any can be identified.
-    /// `causes` will only be provided for the error_type `ASSET_REPORT_ERROR`. `causes` will also not be
-    /// populated inside an error nested within a warning object.
-    pub causes: Option<Vec<String>>,
-    /// The HTTP status code associated with the error. This will only be returned in the response
-    /// body when the error information is provided via a webhook.
-    pub status: i32,
-    /// The URL of a Plaid documentation page with more information about the error
-    pub documentation_url: Option<String>,
-    /// Suggested steps for resolving the error
-    pub suggested_action: Option<String>,
+/// Confirmation that an `update_webhook` request took effect, obtained from
+/// `WebhookUpdateResponse::confirm`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WebhookUpdateConfirmation {
+    /// The ID of the Item whose webhook was updated.
+    pub item_id: String,
+
+    /// The new webhook URL.
+    pub new_webhook_url: String,
 }
 
+/// The type of a `WebhookError`.
+#[deprecated(note = "webhooks carry an `ApiError`, use `ErrorType` instead")]
+pub type WebhookErrorType = crate::ErrorType;
+
+/// The error carried by a webhook.
+#[deprecated(note = "webhooks carry an `ApiError`, use it instead")]
+pub type WebhookError = crate::ApiError;
+
 /// The type of webhook
-#[derive(Serialize, Deserialize, Debug)]
-#[serde(tag = "webhook_type")]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug)]
 pub enum WebhookType {
     /// Webhook relating to `Item`
     Item {
         /// Content of the Webhook
+        content: crate::ItemWebhook,
+    },
+    /// Webhook relating to `Assets`
+    Assets {
+        /// Content of the Webhook
+        content: crate::AssetsWebhook,
+    },
+    /// A webhook of a type not yet modeled by this library, e.g. `TRANSFER`.
+    Unknown {
+        /// The `webhook_type` of the webhook.
+        webhook_type: String,
+        /// The fields of the webhook, including `webhook_type`.
+        raw: serde_json::Value,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "webhook_type")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum KnownWebhookType {
+    Item {
         #[serde(flatten)]
         content: crate::ItemWebhook,
     },
+    Assets {
+        #[serde(flatten)]
+        content: crate::AssetsWebhook,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(tag = "webhook_type")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum KnownWebhookTypeRef<'a> {
+    Item {
+        #[serde(flatten)]
+        content: &'a crate::ItemWebhook,
+    },
+    Assets {
+        #[serde(flatten)]
+        content: &'a crate::AssetsWebhook,
+    },
+}
+
+impl Serialize for WebhookType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            WebhookType::Item { content } => {
+                KnownWebhookTypeRef::Item { content }.serialize(serializer)
+            }
+            WebhookType::Assets { content } => {
+                KnownWebhookTypeRef::Assets { content }.serialize(serializer)
+            }
+            WebhookType::Unknown { raw, .. } => raw.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for WebhookType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = serde_json::Value::deserialize(deserializer)?;
+        let webhook_type = match raw.get("webhook_type").and_then(|value| value.as_str()) {
+            Some(webhook_type) => webhook_type.to_string(),
+            None => return Err(D::Error::missing_field("webhook_type")),
+        };
+
+        match webhook_type.as_str() {
+            "ITEM" | "ASSETS" => match KnownWebhookType::deserialize(raw) {
+                Ok(KnownWebhookType::Item { content }) => Ok(WebhookType::Item { content }),
+                Ok(KnownWebhookType::Assets { content }) => Ok(WebhookType::Assets { content }),
+                Err(error) => Err(D::Error::custom(error)),
+            },
+            _ => Ok(WebhookType::Unknown { webhook_type, raw }),
+        }
+    }
 }
 
 /// Top level webhook struct
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Webhook {
     /// The type of webhook
     #[serde(flatten)]
@@ -98,5 +175,321 @@ pub struct Webhook {
     /// further classified by `error_type`. In general, `200 HTTP` codes correspond to success, `40X` codes
     /// are for developer- or user-related failures, and `50X` codes are for Plaid-related issues.
     /// Error fields will be `null` if no error has occurred.
-    pub error: Option<WebhookError>,
+    pub error: Option<crate::ApiError>,
+}
+
+impl Webhook {
+    /// Parses a webhook from its raw body.
+    ///
+    /// Webhooks of a type not yet modeled by this library are parsed as
+    /// `WebhookType::Unknown` rather than failing, and so are their errors
+    /// of a type not yet modeled, as `ErrorType::Unknown`.
+    pub fn from_slice(body: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(body)
+    }
+
+    /// Calls the method of the `WebhookHandler` corresponding to this webhook.
+    pub fn dispatch(&self, handler: &mut impl WebhookHandler) {
+        use crate::{AssetsWebhookCode, ItemWebhookCode};
+
+        let error = self.error.as_ref();
+        match &self.webhook_type {
+            WebhookType::Item { content } => {
+                let item_id = content.item_id.as_str();
+                match &content.webhook_code {
+                    ItemWebhookCode::Error => handler.on_item_error(item_id, error),
+                    ItemWebhookCode::LoginRepaired => handler.on_login_repaired(item_id),
+                    ItemWebhookCode::NewAccountsAvailable => {
+                        handler.on_new_accounts_available(item_id)
+                    }
+                    ItemWebhookCode::PendingExpiration {
+                        consent_expiration_time,
+                    } => handler.on_pending_expiration(item_id, consent_expiration_time),
+                    ItemWebhookCode::UserPermissionRevoked => {
+                        handler.on_user_permission_revoked(item_id)
+                    }
+                    ItemWebhookCode::WebhookUpdateAcknowledged { new_webhook_url } => {
+                        handler.on_webhook_update_acknowledged(item_id, new_webhook_url)
+                    }
+                }
+            }
+            WebhookType::Assets { content } => {
+                let asset_report_id = content.asset_report_id.as_str();
+                match &content.webhook_code {
+                    AssetsWebhookCode::ProductReady { report_type } => {
+                        handler.on_assets_product_ready(asset_report_id, *report_type)
+                    }
+                    AssetsWebhookCode::Error => handler.on_assets_error(asset_report_id, error),
+                }
+            }
+            WebhookType::Unknown { webhook_type, raw } => {
+                handler.on_unknown_webhook(webhook_type, raw)
+            }
+        }
+    }
+}
+
+/// Handles webhooks routed by `Webhook::dispatch`.
+///
+/// Every method does nothing by default, so only the webhooks of interest need
+/// to be handled.
+#[allow(unused_variables)]
+pub trait WebhookHandler {
+    /// Handles an `ITEM: ERROR` webhook.
+    fn on_item_error(&mut self, item_id: &str, error: Option<&crate::ApiError>) {}
+
+    /// Handles an `ITEM: LOGIN_REPAIRED` webhook.
+    fn on_login_repaired(&mut self, item_id: &str) {}
+
+    /// Handles an `ITEM: NEW_ACCOUNTS_AVAILABLE` webhook.
+    fn on_new_accounts_available(&mut self, item_id: &str) {}
+
+    /// Handles an `ITEM: PENDING_EXPIRATION` webhook.
+    fn on_pending_expiration(&mut self, item_id: &str, consent_expiration_time: &str) {}
+
+    /// Handles an `ITEM: USER_PERMISSION_REVOKED` webhook.
+    fn on_user_permission_revoked(&mut self, item_id: &str) {}
+
+    /// Handles an `ITEM: WEBHOOK_UPDATE_ACKNOWLEDGED` webhook.
+    fn on_webhook_update_acknowledged(&mut self, item_id: &str, new_webhook_url: &str) {}
+
+    /// Handles an `ASSETS: PRODUCT_READY` webhook.
+    fn on_assets_product_ready(
+        &mut self,
+        asset_report_id: &str,
+        report_type: Option<crate::AssetReportType>,
+    ) {
+    }
+
+    /// Handles an `ASSETS: ERROR` webhook.
+    fn on_assets_error(&mut self, asset_report_id: &str, error: Option<&crate::ApiError>) {}
+
+    /// Handles a webhook of a type not yet modeled by this library.
+    fn on_unknown_webhook(&mut self, webhook_type: &str, raw: &serde_json::Value) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{fixtures, AssetReportType, AssetsWebhookCode};
+
+    #[test]
+    fn can_deserialize_assets_product_ready() {
+        let webhook: Webhook = serde_json::from_value(json!({
+            "webhook_type": "ASSETS",
+            "webhook_code": "PRODUCT_READY",
+            "asset_report_id": "47dfc92b-bba3-4583-809e-ce871b321f05",
+            "report_type": "FULL",
+            "environment": "production"
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            webhook.webhook_type,
+            WebhookType::Assets {
+                content: crate::AssetsWebhook {
+                    webhook_code: AssetsWebhookCode::ProductReady {
+                        report_type: Some(AssetReportType::Full)
+                    },
+                    ..
+                }
+            }
+        ));
+        assert!(webhook.error.is_none());
+    }
+
+    #[test]
+    fn can_deserialize_assets_error() {
+        let webhook: Webhook = serde_json::from_value(json!({
+            "webhook_type": "ASSETS",
+            "webhook_code": "ERROR",
+            "asset_report_id": "47dfc92b-bba3-4583-809e-ce871b321f05",
+            "error": {
+                "display_message": null,
+                "error_code": "PRODUCT_NOT_ENABLED",
+                "error_message": "the 'assets' product is not enabled for the following access tokens: access-sandbox-fb88b20c-7b74-4197-8d01-0ab122dad0bc. please ensure that 'assets' is included in the 'product' array when initializing Link and create the Item(s) again.",
+                "error_type": "ASSET_REPORT_ERROR",
+                "request_id": "m8MDnv9okwxFNBV",
+                "causes": [{
+                    "item_id": "pZ942ZA5bDzVvwJ2EaaLX9XaKdxdNNFkjBxnRg",
+                    "display_message": null,
+                    "error_code": "PRODUCT_NOT_ENABLED",
+                    "error_message": "the 'assets' product is not enabled for the following access tokens: access-sandbox-fb88b20c-7b74-4197-8d01-0ab122dad0bc. please ensure that 'assets' is included in the 'product' array when initializing Link and create the Item(s) again.",
+                    "error_type": "ASSET_REPORT_ERROR",
+                    "request_id": "m8MDnv9okwxFNBV"
+                }],
+                "status": 400,
+                "documentation_url": "https://plaid.com/docs/?ref=error#asset-report-errors",
+                "suggested_action": null
+            },
+            "environment": "production"
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            webhook.webhook_type,
+            WebhookType::Assets {
+                content: crate::AssetsWebhook {
+                    webhook_code: AssetsWebhookCode::Error,
+                    ..
+                }
+            }
+        ));
+        assert!(matches!(
+            webhook.error,
+            Some(crate::ApiError {
+                error_type: crate::ErrorType::AssetReportError,
+                ..
+            })
+        ));
+
+        let error = webhook.error.unwrap();
+        assert_eq!(
+            error.causes[0].item_id.as_deref(),
+            Some("pZ942ZA5bDzVvwJ2EaaLX9XaKdxdNNFkjBxnRg")
+        );
+        assert_eq!(error.causes[0].error.error_code, "PRODUCT_NOT_ENABLED");
+        assert!(error.is_client_error());
+        assert!(!error.is_server_error());
+        assert!(!error.is_rate_limited());
+    }
+
+    #[test]
+    fn can_deserialize_item_login_repaired() {
+        let body = br#"{
+            "webhook_type": "ITEM",
+            "webhook_code": "LOGIN_REPAIRED",
+            "item_id": "gAXlMgVEw5uEGoQnnXZ6tn9E7Mn3LBc4PJVKZ",
+            "environment": "production"
+        }"#;
+        let webhook = Webhook::from_slice(body).unwrap();
+
+        match webhook.webhook_type {
+            WebhookType::Item { content } => {
+                assert!(matches!(
+                    content.webhook_code,
+                    crate::ItemWebhookCode::LoginRepaired
+                ));
+                assert_eq!(content.item_id, "gAXlMgVEw5uEGoQnnXZ6tn9E7Mn3LBc4PJVKZ");
+            }
+            other => panic!("unexpected webhook type: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn keeps_unknown_webhook_type() {
+        let body = br#"{
+            "webhook_type": "TRANSFER",
+            "webhook_code": "TRANSFER_EVENTS_UPDATE",
+            "environment": "production",
+            "error": null
+        }"#;
+        let webhook = Webhook::from_slice(body).unwrap();
+
+        match &webhook.webhook_type {
+            WebhookType::Unknown { webhook_type, raw } => {
+                assert_eq!(webhook_type, "TRANSFER");
+                assert_eq!(raw["webhook_code"], "TRANSFER_EVENTS_UPDATE");
+            }
+            other => panic!("unexpected webhook type: {:?}", other),
+        }
+        assert!(webhook.error.is_none());
+
+        let value = serde_json::to_value(&webhook).unwrap();
+        assert_eq!(value["webhook_type"], "TRANSFER");
+        assert_eq!(value["environment"], "production");
+
+        let result = Webhook::from_slice(br#"{"webhook_type":"ITEM","webhook_code":"ERROR"}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn keeps_unknown_webhook_type_with_error() {
+        let body = br#"{
+            "webhook_type": "TRANSFER",
+            "webhook_code": "TRANSFER_EVENTS_UPDATE",
+            "environment": "production",
+            "error": {
+                "display_message": null,
+                "error_code": "TRANSFER_LIMIT_REACHED",
+                "error_message": "the transfer limit was reached",
+                "error_type": "TRANSFER_ERROR",
+                "request_id": null,
+                "causes": [{
+                    "item_id": "DWVAAPWq4RHGlEaNyGKRTAnPLaEmo8Cvq7na6",
+                    "display_message": null,
+                    "error_code": "TRANSACTIONS_LIMIT",
+                    "error_message": "rate limit exceeded",
+                    "error_type": "TRANSACTIONS_ERROR"
+                }],
+                "status": 400
+            }
+        }"#;
+        let webhook = Webhook::from_slice(body).unwrap();
+
+        assert!(matches!(webhook.webhook_type, WebhookType::Unknown { .. }));
+        let error = webhook.error.unwrap();
+        assert_eq!(error.error_type, crate::ErrorType::Unknown);
+        assert_eq!(error.error_code, "TRANSFER_LIMIT_REACHED");
+        assert_eq!(error.causes[0].error.error_type, crate::ErrorType::Unknown);
+    }
+
+    #[test]
+    fn can_dispatch() {
+        #[derive(Default)]
+        struct Handler {
+            revoked: Vec<String>,
+        }
+
+        impl WebhookHandler for Handler {
+            fn on_user_permission_revoked(&mut self, item_id: &str) {
+                self.revoked.push(item_id.to_string());
+            }
+        }
+
+        let webhook: Webhook = serde_json::from_value(json!({
+            "webhook_type": "ITEM",
+            "webhook_code": "USER_PERMISSION_REVOKED",
+            "item_id": "wz666MBjYWTp2PDzzggYhM6oWWmBb",
+            "error": null
+        }))
+        .unwrap();
+
+        let mut handler = Handler::default();
+        webhook.dispatch(&mut handler);
+        assert_eq!(handler.revoked, vec!["wz666MBjYWTp2PDzzggYhM6oWWmBb"]);
+    }
+
+    #[test]
+    fn can_confirm_webhook_update() {
+        let mut item = fixtures::item();
+        item["item_id"] = json!("wz666MBjYWTp2PDzzggYhM6oWWmBb");
+        let response: WebhookUpdateResponse = serde_json::from_value(json!({
+            "item": item,
+            "request_id": "vYK11LNTfRoAMbj"
+        }))
+        .unwrap();
+
+        let mut value = json!({
+            "webhook_type": "ITEM",
+            "webhook_code": "WEBHOOK_UPDATE_ACKNOWLEDGED",
+            "item_id": "wz666MBjYWTp2PDzzggYhM6oWWmBb",
+            "new_webhook_url": "https://www.genericwebhookurl.com/webhook",
+            "error": null
+        });
+        let webhook: Webhook = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(
+            response.confirm(&webhook),
+            Some(WebhookUpdateConfirmation {
+                item_id: "wz666MBjYWTp2PDzzggYhM6oWWmBb".to_string(),
+                new_webhook_url: "https://www.genericwebhookurl.com/webhook".to_string(),
+            })
+        );
+
+        value["new_webhook_url"] = json!("https://www.example.com/webhook");
+        let webhook: Webhook = serde_json::from_value(value).unwrap();
+        assert_eq!(response.confirm(&webhook), None);
+    }
 }